@@ -35,6 +35,27 @@ mod consts {
         pub const PRIME_INIT_D: u16 = 0xf223;
     }
 
+    // Word mode starts from the complement of the byte-mode factors, so it is
+    // domain-separated from byte mode by a distinct IV and padding rule
+    pub mod words {
+        pub const WORDS_INIT_A: u16 = !super::initial_primes::PRIME_INIT_A;
+        pub const WORDS_INIT_B: u16 = !super::initial_primes::PRIME_INIT_B;
+        pub const WORDS_INIT_C: u16 = !super::initial_primes::PRIME_INIT_C;
+        pub const WORDS_INIT_D: u16 = !super::initial_primes::PRIME_INIT_D;
+        pub const WORDS_PAD_MARKER: u16 = 0x8000;
+    }
+
+    // PoxHash-MD starts from the byte-swapped initial primes, since its padded blocks
+    // can equal an unpadded octopad message of a whole number of blocks
+    pub mod md {
+        pub const MD_INIT_A: u16 = super::initial_primes::PRIME_INIT_A.swap_bytes();
+        pub const MD_INIT_B: u16 = super::initial_primes::PRIME_INIT_B.swap_bytes();
+        pub const MD_INIT_C: u16 = super::initial_primes::PRIME_INIT_C.swap_bytes();
+        pub const MD_INIT_D: u16 = super::initial_primes::PRIME_INIT_D.swap_bytes();
+        pub const MD_PAD_MARKER: u16 = 0x80;
+        pub const MD_LENGTH_OFFSET: usize = 56;
+    }
+
     // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#size-constants
     pub mod size_values {
         pub const ROUND_PRIME_NUM: usize = 90;
@@ -109,6 +130,59 @@ mod consts {
         pub const SIZE_BIONOM: usize = 6;
     }
 
+    pub mod text {
+        pub const LATIN1_MAX: char = '\u{ff}';
+        pub const CRLF: &'static str = "\r\n";
+        pub const LF: &'static str = "\n";
+    }
+
+    pub mod stream {
+        // octopad appends at most BLOCK_NUM - 1 words and moves at most 255 bytes for each,
+        // so it never reads past this many bytes from the start of the message.
+        pub const OCTOPAD_REACH: usize = 16320;
+    }
+
+    pub mod hashable {
+        pub const FALSE_BYTE: u8 = 0;
+        pub const TRUE_BYTE: u8 = 1;
+        pub const NONE_TAG: u8 = 0;
+        pub const SOME_TAG: u8 = 1;
+    }
+
+    pub mod wire {
+        pub const WIRE_VERSION: u8 = 1;
+        pub const WIRE_SIZE: usize = 9;
+    }
+
+    // https://github.com/multiformats/multihash, https://github.com/multiformats/multibase
+    pub mod multihash {
+        pub const POXHASH_SIZE: usize = 8;
+        pub const POXHASH_CODE: u64 = 0x300a00;
+        pub const POXHASH_8_CODE: u64 = 0x300a01;
+        pub const POXHASH_16_CODE: u64 = 0x300a02;
+        pub const POXHASH_32_CODE: u64 = 0x300a03;
+        pub const POXHASH_WORDS_CODE: u64 = 0x300a10;
+        pub const POXHASH_MD_CODE: u64 = 0x300a20;
+        pub const CODES: &'static [(u64, usize, &'static str)] = &[
+            (POXHASH_CODE, POXHASH_SIZE, "poxhash-64"),
+            (POXHASH_8_CODE, 1, "poxhash-64-trunc-8"),
+            (POXHASH_16_CODE, 2, "poxhash-64-trunc-16"),
+            (POXHASH_32_CODE, 4, "poxhash-64-trunc-32"),
+            (POXHASH_WORDS_CODE, POXHASH_SIZE, "poxhash-words-64"),
+            (POXHASH_MD_CODE, POXHASH_SIZE, "poxhash-md-64"),
+        ];
+        pub const VARINT_MAX_SIZE: usize = 9;
+        pub const VARINT_PAYLOAD_BITS: u32 = 7;
+        pub const VARINT_PAYLOAD_MASK: u8 = 0x7f;
+        pub const VARINT_CONTINUE: u8 = 0x80;
+        pub const BASE16_PREFIX: char = 'f';
+        pub const BASE32_PREFIX: char = 'b';
+        pub const BASE58BTC_PREFIX: char = 'z';
+        pub const BASE64URL_PREFIX: char = 'u';
+        pub const BASE16_CHARS: &'static [u8; 16] = b"0123456789abcdef";
+        pub const BASE32_LOWER_CHARS: &'static [u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+    }
+
     pub mod conversion {
         pub const SEX_SIZE: usize = 3;
         pub const VIG_SIZE: usize = 4;
@@ -118,14 +192,6 @@ mod consts {
         pub const OCT_SIZE: usize = 6;
         pub const SEN_SIZE: usize = 7;
         pub const BIN_SIZE: usize = 16;
-        pub const SEX_BASE: u16 = 60;
-        pub const VIG_BASE: u16 = 20;
-        pub const HEX_BASE: u16 = 16;
-        pub const TET_BASE: u16 = 14;
-        pub const DUO_BASE: u16 = 12;
-        pub const OCT_BASE: u16 = 8;
-        pub const SEN_BASE: u16 = 6;
-        pub const BIN_BASE: u16 = 2;
 
         pub const SEX_CHARS: [char; 60] = [
             '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
@@ -148,6 +214,71 @@ mod consts {
         pub const OCT_CHARS: [char; 8] = ['0', '1', '2', '3', '4', '5', '6', '7'];
         pub const SEN_CHARS: [char; 6] = ['0', '1', '2', '3', '4', '5'];
         pub const BIN_CHARS: [char; 2] = ['0', '1'];
+
+        pub const RADIX_MIN: usize = 2;
+        pub const RADIX_MAX: usize = 255;
+        pub const BASE36_SIZE: usize = 4;
+        pub const BASE62_SIZE: usize = 3;
+        pub const BASE36_CHARS: [char; 36] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+            'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x',
+            'y', 'z',
+        ];
+        pub const BASE62_CHARS: [char; 62] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
+            'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X',
+            'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+            'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+        ];
+
+        pub const BASE32_SIZE: usize = 13;
+        pub const BASE32_PADDED_SIZE: usize = 16;
+        pub const BASE64_SIZE: usize = 11;
+        pub const BASE64_PADDED_SIZE: usize = 12;
+        pub const PROQUINT_QUINT_SIZE: usize = 5;
+        pub const PROQUINT_SIZE: usize = 23;
+        pub const PADDING_CHAR: char = '=';
+        pub const PROQUINT_SEPARATOR: &'static str = "-";
+        pub const BASE32_CHARS: &'static [u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+        pub const BASE64URL_CHARS: &'static [u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+        pub const BASE58_CHARS: &'static [u8; 58] =
+            b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        pub const PROQUINT_CONSONANTS: &'static [u8; 16] = b"bdfghjklmnprstvz";
+        pub const PROQUINT_VOWELS: &'static [u8; 4] = b"aiou";
+
+        // Frozen: one word per byte value, no two sharing their first four letters
+        pub const MNEMONIC_WORDS: [&'static str; 256] = [
+            "acid", "acorn", "actor", "adobe", "alarm", "album", "alley", "amber", "angle",
+            "ankle", "apple", "apron", "atlas", "attic", "audio", "autumn", "bacon", "badge",
+            "bagel", "baker", "banjo", "barrel", "basket", "beach", "beaver", "bench", "berry",
+            "bingo", "board", "bonus", "border", "bottle", "brick", "bridge", "bronze", "brush",
+            "bucket", "bugle", "bundle", "butter", "cable", "cactus", "camel", "candle", "canyon",
+            "carpet", "carrot", "castle", "cello", "chalk", "cheese", "cherry", "cider", "cinema",
+            "circle", "citrus", "cloud", "clover", "cobalt", "cocoa", "comet", "copper", "coral",
+            "cotton", "crayon", "crown", "cube", "cymbal", "dancer", "delta", "denim", "desert",
+            "domino", "donkey", "dragon", "drum", "dune", "dust", "eagle", "easel", "elbow",
+            "elder", "ember", "engine", "escape", "fabric", "falcon", "fence", "fiddle", "field",
+            "finch", "flame", "forest", "fossil", "fox", "frost", "funnel", "galaxy", "garden",
+            "garlic", "geyser", "ginger", "globe", "goblet", "guitar", "gypsum", "hammer",
+            "harbor", "hazel", "helmet", "hermit", "honey", "hotel", "humble", "husky", "icon",
+            "iguana", "index", "indigo", "inlet", "island", "ivory", "jacket", "jaguar", "jester",
+            "jigsaw", "jockey", "judge", "jungle", "kayak", "kernel", "kettle", "kiosk", "kitten",
+            "koala", "ladder", "laptop", "lava", "lemon", "lentil", "linen", "lizard", "locket",
+            "lotus", "lunar", "magnet", "mango", "maple", "meadow", "melon", "meteor", "mirror",
+            "monkey", "mosaic", "motor", "muffin", "napkin", "nectar", "needle", "nephew", "noble",
+            "noodle", "north", "nugget", "oasis", "ocean", "octave", "olive", "onion", "opal",
+            "orbit", "orchid", "oyster", "paddle", "palace", "panda", "parrot", "pastry", "peanut",
+            "pebble", "pepper", "piano", "pickle", "pilot", "plum", "pocket", "polar", "pony",
+            "quail", "quartz", "queen", "quiet", "quiver", "rabbit", "radar", "radish", "raven",
+            "record", "reef", "ribbon", "river", "robot", "rocket", "rodeo", "rudder", "saddle",
+            "salmon", "sandal", "scarf", "shadow", "silver", "sketch", "sonnet", "spider",
+            "squash", "statue", "sunset", "table", "tango", "teapot", "ticket", "tiger", "timber",
+            "toast", "topaz", "torch", "tulip", "tunnel", "tuxedo", "urban", "valley", "velvet",
+            "visor", "voyage", "waffle", "wagon", "walrus", "wasabi", "water", "weasel", "wheat",
+            "willow", "window", "winter", "wombat", "xenon", "yacht", "yarn", "yodel", "yogurt",
+            "yonder", "zebra", "zero", "zigzag", "zinc", "zipper",
+        ];
     }
 }
 
@@ -199,17 +330,7 @@ mod tools {
 
 // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-g-conversion--preparation-prep-methods
 mod convert {
-    use super::{consts::*, types};
-
-    macro_rules! convert_decimal_to_base {
-        ($base: path, $size: path, $chars: path, $res: ident, $dec: ident, $offset: ident) => {{
-            let mut decimal = $dec;
-            for i in ($offset * $size..($offset * $size) + $size).rev() {
-                $res[i] = $chars[(decimal % $base) as usize];
-                decimal /= $base;
-            }
-        }};
-    }
+    use super::{consts::*, types, ByteEncodingError, MultihashError};
 
     fn single_word_to_byte(word: u16) -> (u8, u8) {
         let lower: u8 = (word & masks::WORD_ZZFF) as u8;
@@ -225,6 +346,8 @@ mod convert {
         res
     }
 
+    // All of these are little-endian, which SPEC.md makes canonical. The big-endian
+    // forms are on PoxDigest.
     pub fn word_array_to_byte_array(word_array: types::ArrTypeRef) -> [u8; 8] {
         let (b0, b1) = single_word_to_byte(word_array[0]);
         let (b2, b3) = single_word_to_byte(word_array[1]);
@@ -239,143 +362,244 @@ mod convert {
         [lower, upper]
     }
 
-    pub fn word_array_to_sex_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::SEX_SIZE * size_values::PORTION_NUM];
+    pub fn word_array_to_quad(word_array: types::ArrTypeRef) -> u64 {
+        let mut quad = 0u64;
+
+        quad |= word_array[0] as u64;
+        quad |= (word_array[1] as u64) << 16;
+        quad |= (word_array[2] as u64) << 32;
+        quad |= (word_array[3] as u64) << 48;
+
+        quad
+    }
+
+    pub fn byte_array_to_word_array(byte_array: &[u8; 8]) -> types::ArrType {
+        let mut word_array = [0u16; 4];
         for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::SEX_BASE,
-                conversion::SEX_SIZE,
-                conversion::SEX_CHARS,
-                digest,
-                word,
-                i
-            }
+            word_array[i] = byte_array[i * 2] as u16
+                | (byte_array[i * 2 + 1] as u16) << bit_values::BYTE_WIDTH_U16;
         }
-        return digest.into_iter().collect();
+        word_array
     }
 
-    pub fn word_array_to_vig_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::VIG_SIZE * size_values::PORTION_NUM];
-        for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::VIG_BASE,
-                conversion::VIG_SIZE,
-                conversion::VIG_CHARS,
-                digest,
-                word,
-                i
+    // https://datatracker.ietf.org/doc/html/rfc4648
+    pub fn byte_array_to_bit_groups(byte_array: &[u8], alphabet: &[u8], group_bits: u32) -> String {
+        let mask = (1u32 << group_bits) - 1;
+        let mut encoded = String::new();
+        let mut buffer = 0u32;
+        let mut buffered_bits = 0u32;
+        for byte in byte_array {
+            buffer = (buffer << 8) | *byte as u32;
+            buffered_bits += 8;
+            while buffered_bits >= group_bits {
+                buffered_bits -= group_bits;
+                encoded.push(alphabet[((buffer >> buffered_bits) & mask) as usize] as char);
             }
+            buffer &= (1u32 << buffered_bits) - 1;
+        }
+        if buffered_bits > 0 {
+            encoded
+                .push(alphabet[((buffer << (group_bits - buffered_bits)) & mask) as usize] as char);
         }
-        return digest.into_iter().collect();
+        encoded
     }
 
-    pub fn word_array_to_hex_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::HEX_SIZE * size_values::PORTION_NUM];
-        for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::HEX_BASE,
-                conversion::HEX_SIZE,
-                conversion::HEX_CHARS,
-                digest,
-                word,
-                i
+    pub fn bit_groups_to_bytes(
+        digits: &[char],
+        alphabet: &[u8],
+        group_bits: u32,
+    ) -> Result<Vec<u8>, ByteEncodingError> {
+        let mut bytes = Vec::with_capacity(digits.len() * group_bits as usize / 8);
+        let mut buffer = 0u32;
+        let mut buffered_bits = 0u32;
+        for (position, digit) in digits.iter().enumerate() {
+            let value = match alphabet.iter().position(|c| *c as char == *digit) {
+                Some(value) => value as u32,
+                None => {
+                    return Err(ByteEncodingError::InvalidChar {
+                        position,
+                        found: *digit,
+                    })
+                }
+            };
+            buffer = (buffer << group_bits) | value;
+            buffered_bits += group_bits;
+            if buffered_bits >= 8 {
+                buffered_bits -= 8;
+                bytes.push((buffer >> buffered_bits) as u8);
             }
+            buffer &= (1u32 << buffered_bits) - 1;
         }
-        return digest.into_iter().collect();
+        // Leftover bits must be padding: fewer than one digit's worth, all zero
+        if buffer != 0 || buffered_bits >= group_bits {
+            return Err(ByteEncodingError::TrailingBits);
+        }
+        Ok(bytes)
     }
 
-    pub fn word_array_to_tet_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::TET_SIZE * size_values::PORTION_NUM];
-        for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::TET_BASE,
-                conversion::TET_SIZE,
-                conversion::TET_CHARS,
-                digest,
-                word,
-                i
-            }
+    // https://github.com/multiformats/unsigned-varint
+    pub fn u64_to_varint(mut value: u64, varint: &mut Vec<u8>) {
+        while value > multihash::VARINT_PAYLOAD_MASK as u64 {
+            varint
+                .push((value as u8 & multihash::VARINT_PAYLOAD_MASK) | multihash::VARINT_CONTINUE);
+            value >>= multihash::VARINT_PAYLOAD_BITS;
         }
-        return digest.into_iter().collect();
+        varint.push(value as u8);
     }
 
-    pub fn word_array_to_duo_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::DUO_SIZE * size_values::PORTION_NUM];
-        for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::DUO_BASE,
-                conversion::DUO_SIZE,
-                conversion::DUO_CHARS,
-                digest,
-                word,
-                i
+    pub fn varint_to_u64(varint: &[u8]) -> Result<(u64, usize), MultihashError> {
+        let mut value = 0u64;
+        for (position, byte) in varint.iter().take(multihash::VARINT_MAX_SIZE).enumerate() {
+            value |= ((byte & multihash::VARINT_PAYLOAD_MASK) as u64)
+                << (position as u32 * multihash::VARINT_PAYLOAD_BITS);
+            if byte & multihash::VARINT_CONTINUE == 0 {
+                // Only the minimal encoding is accepted: no trailing zero groups
+                if *byte == 0 && position > 0 {
+                    return Err(MultihashError::Varint);
+                }
+                return Ok((value, position + 1));
             }
         }
-        return digest.into_iter().collect();
+        Err(MultihashError::Varint)
     }
 
-    pub fn word_array_to_oct_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::OCT_SIZE * size_values::PORTION_NUM];
-        for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::OCT_BASE,
-                conversion::OCT_SIZE,
-                conversion::OCT_CHARS,
-                digest,
-                word,
-                i
+    // https://en.bitcoin.it/wiki/Base58Check_encoding#Base58_symbol_chart
+    pub fn bytes_to_base58(bytes: &[u8]) -> String {
+        let zeros = bytes.iter().take_while(|b| **b == 0).count();
+        let mut digits: Vec<u8> = Vec::new();
+        for byte in &bytes[zeros..] {
+            let mut carry = *byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
             }
         }
-        return digest.into_iter().collect();
+        digits.resize(digits.len() + zeros, 0);
+        digits
+            .iter()
+            .rev()
+            .map(|d| conversion::BASE58_CHARS[*d as usize] as char)
+            .collect()
     }
 
-    pub fn word_array_to_sen_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::SEN_SIZE * size_values::PORTION_NUM];
-        for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::SEN_BASE,
-                conversion::SEN_SIZE,
-                conversion::SEN_CHARS,
-                digest,
-                word,
-                i
+    pub fn base58_to_bytes(digits: &[char]) -> Result<Vec<u8>, ByteEncodingError> {
+        let zero = conversion::BASE58_CHARS[0] as char;
+        let zeros = digits.iter().take_while(|c| **c == zero).count();
+        let mut bytes: Vec<u8> = Vec::new();
+        for (position, digit) in digits.iter().enumerate().skip(zeros) {
+            let mut carry = match conversion::BASE58_CHARS
+                .iter()
+                .position(|c| *c as char == *digit)
+            {
+                Some(index) => index as u32,
+                None => {
+                    return Err(ByteEncodingError::InvalidChar {
+                        position,
+                        found: *digit,
+                    })
+                }
+            };
+            for byte in bytes.iter_mut() {
+                carry += (*byte as u32) * 58;
+                *byte = carry as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push(carry as u8);
+                carry >>= 8;
             }
         }
-        return digest.into_iter().collect();
+        bytes.resize(bytes.len() + zeros, 0);
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    // https://arxiv.org/html/0901.4016
+    pub fn byte_array_to_proquint(byte_array: &[u8; 8]) -> String {
+        let mut quints = Vec::with_capacity(size_values::PORTION_NUM);
+        for pair in byte_array.chunks(2) {
+            let word = (pair[0] as usize) << 8 | pair[1] as usize;
+            let quint = [
+                conversion::PROQUINT_CONSONANTS[word >> 12 & 0xf],
+                conversion::PROQUINT_VOWELS[word >> 10 & 0x3],
+                conversion::PROQUINT_CONSONANTS[word >> 6 & 0xf],
+                conversion::PROQUINT_VOWELS[word >> 4 & 0x3],
+                conversion::PROQUINT_CONSONANTS[word & 0xf],
+            ];
+            quints.push(quint.iter().map(|c| *c as char).collect::<String>());
+        }
+        quints.join(conversion::PROQUINT_SEPARATOR)
     }
 
-    pub fn word_array_to_bin_digest(word_array: types::ArrTypeRef) -> String {
-        let mut digest = vec!['0'; conversion::BIN_SIZE * size_values::PORTION_NUM];
+    pub fn proquint_to_byte_array(digits: &[char]) -> Result<[u8; 8], ByteEncodingError> {
+        let mut byte_array = [0u8; 8];
         for i in 0..size_values::PORTION_NUM {
-            let word = word_array[i];
-            convert_decimal_to_base! {
-                conversion::BIN_BASE,
-                conversion::BIN_SIZE,
-                conversion::BIN_CHARS,
-                digest,
-                word,
-                i
+            let start = i * (conversion::PROQUINT_QUINT_SIZE + 1);
+            if i > 0 && digits[start - 1].to_string() != conversion::PROQUINT_SEPARATOR {
+                return Err(ByteEncodingError::InvalidChar {
+                    position: start - 1,
+                    found: digits[start - 1],
+                });
+            }
+            let mut word = 0usize;
+            for j in 0..conversion::PROQUINT_QUINT_SIZE {
+                let letters: &[u8] = if j % 2 == 0 {
+                    conversion::PROQUINT_CONSONANTS
+                } else {
+                    conversion::PROQUINT_VOWELS
+                };
+                let digit = digits[start + j];
+                match letters.iter().position(|c| *c as char == digit) {
+                    Some(value) => word = word << (if j % 2 == 0 { 4 } else { 2 }) | value,
+                    None => {
+                        return Err(ByteEncodingError::InvalidChar {
+                            position: start + j,
+                            found: digit,
+                        })
+                    }
+                }
             }
+            byte_array[i * 2] = (word >> 8) as u8;
+            byte_array[i * 2 + 1] = word as u8;
         }
-        return digest.into_iter().collect();
+        Ok(byte_array)
     }
 
-    pub fn word_array_to_quad(word_array: types::ArrTypeRef) -> u64 {
-        let mut quad = 0u64;
-
-        quad |= word_array[0] as u64;
-        quad |= (word_array[1] as u64) << 16;
-        quad |= (word_array[2] as u64) << 32;
-        quad |= (word_array[3] as u64) << 48;
+    pub fn byte_array_to_mnemonic(byte_array: &[u8; 8]) -> String {
+        byte_array
+            .iter()
+            .map(|b| conversion::MNEMONIC_WORDS[*b as usize])
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
 
-        quad
+    pub fn mnemonic_to_byte_array(encoded: &str) -> Result<[u8; 8], ByteEncodingError> {
+        let words = encoded.split_whitespace().collect::<Vec<&str>>();
+        if words.len() != 8 {
+            return Err(ByteEncodingError::WordCount(words.len()));
+        }
+        let mut byte_array = [0u8; 8];
+        for (position, word) in words.iter().enumerate() {
+            let lowered = word.to_lowercase();
+            match conversion::MNEMONIC_WORDS
+                .iter()
+                .position(|w| *w == lowered)
+            {
+                Some(value) => byte_array[position] = value as u8,
+                None => {
+                    return Err(ByteEncodingError::UnknownWord {
+                        position,
+                        found: word.to_string(),
+                    })
+                }
+            }
+        }
+        Ok(byte_array)
     }
 }
 
@@ -400,6 +624,36 @@ mod operations {
             word_vec
         }
 
+        pub fn octopad_tail(head: &[u8], tail: &[u8], message_len: usize) -> Vec<u16> {
+            let mut word_vec = tail.iter().map(|b| *b as u16).collect::<Vec<u16>>();
+            let mut n = message_len;
+            while word_vec.len() % size_values::BLOCK_NUM != 0 {
+                let word = head[n % message_len] as u16;
+                word_vec.push(word ^ (n & masks::MASK_QWORD_14Z2F) as u16);
+                n += word as usize;
+            }
+            word_vec
+        }
+
+        pub fn wordpad(word_array: &[u16]) -> Vec<u16> {
+            let mut word_vec = word_array.to_vec();
+            word_vec.push(words::WORDS_PAD_MARKER);
+            let fill = (size_values::BLOCK_NUM - word_vec.len() % size_values::BLOCK_NUM)
+                % size_values::BLOCK_NUM;
+            word_vec.resize(word_vec.len() + fill, 0);
+            word_vec
+        }
+
+        pub fn mdpad(tail: &[u8], message_len: u64) -> Vec<u16> {
+            let mut word_vec = tail.iter().map(|b| *b as u16).collect::<Vec<u16>>();
+            word_vec.push(md::MD_PAD_MARKER);
+            while word_vec.len() % size_values::BLOCK_NUM != md::MD_LENGTH_OFFSET {
+                word_vec.push(0);
+            }
+            word_vec.extend(message_len.to_le_bytes().iter().map(|b| *b as u16));
+            word_vec
+        }
+
         pub fn omega(num: u32) -> u32 {
             (num & masks::DWORD_4F4Z) >> bit_values::WORD_WIDTH_U32
         }
@@ -594,7 +848,7 @@ mod round {
         }};
     }
 
-    fn apply_alphabet_operation(temp_array: types::ArrTypeRef) -> types::ArrType {
+    pub fn apply_alphabet_operation(temp_array: types::ArrTypeRef) -> types::ArrType {
         let mut temp_array_cpy = tools::copy_array(temp_array);
         temp_array_cpy = alphabet::alpha(&temp_array_cpy);
         temp_array_cpy = alphabet::delta(&temp_array_cpy);
@@ -603,7 +857,7 @@ mod round {
         temp_array_cpy
     }
 
-    fn apply_bahman(temp_array: types::ArrTypeRef, pnum: u16) -> types::ArrType {
+    pub fn apply_bahman(temp_array: types::ArrTypeRef, pnum: u16) -> types::ArrType {
         let mut temp_array_cpy = tools::copy_array(temp_array);
         let cica = (pnum % (size_values::PORTION_NUM as u16)) as usize;
         let mica = ((cica + 1) % size_values::PORTION_NUM) as usize;
@@ -624,7 +878,7 @@ mod round {
         temp_array_cpy
     }
 
-    fn apply_prime(temp_array: types::ArrTypeRef) -> types::ArrType {
+    pub fn apply_prime(temp_array: types::ArrTypeRef) -> types::ArrType {
         let mut temp_array_cpy = tools::copy_array(temp_array);
         for i in 0..size_values::ROUND_PRIME_NUM {
             temp_array_cpy[0] %= prime_arrays::ROUND_PRIMES[i];
@@ -636,7 +890,7 @@ mod round {
         temp_array_cpy
     }
 
-    fn apply_add_temp_to_facts(
+    pub fn apply_add_temp_to_facts(
         factor_array: types::ArrTypeRef,
         temp_array: types::ArrTypeRef,
     ) -> types::ArrType {
@@ -648,7 +902,7 @@ mod round {
         factor_array_cpy
     }
 
-    fn apply_shuffle(temp_array: types::ArrTypeRef) -> types::ArrType {
+    pub fn apply_shuffle(temp_array: types::ArrTypeRef) -> types::ArrType {
         let mut temp_array_cpy = tools::copy_array(temp_array);
         for i in 0..misc::SIZE_BIONOM {
             let (iof, iwith) = misc::COMB_BIONOM[i];
@@ -677,16 +931,21 @@ mod round {
 mod block {
     use super::{consts::*, operations::*, round, tools, types};
 
-    fn apply_bytes(factor_array: types::ArrTypeRef, portion: &[u16], index: u16) -> types::ArrType {
+    pub fn apply_bytes(
+        factor_array: types::ArrTypeRef,
+        portion: &[u16],
+        index: u16,
+    ) -> types::ArrType {
         let tmt = bespoke::tamaam(portion);
         let dca = bespoke::deca(portion);
-        let tmt_odd_factor = bit_values::UINT16_MAX_U16 ^ (tmt % (dca + 2));
-        let dca_odd_factor = bit_values::UINT16_MAX_U16 ^ (dca % (tmt + 3));
+        // Bytes keep these sums small, but full 16-bit message words would overflow a u16
+        let tmt_odd_factor = bit_values::UINT16_MAX_U16 ^ ((tmt as u32 % (dca as u32 + 2)) as u16);
+        let dca_odd_factor = bit_values::UINT16_MAX_U16 ^ ((dca as u32 % (tmt as u32 + 3)) as u16);
 
-        let ng = ((portion[0] + index) % (size_values::PORTION_NUM as u16)) as usize;
-        let chu = ((portion[1] + index) % (size_values::PORTION_NUM as u16)) as usize;
-        let yo = ((portion[2] + index) % (size_values::PORTION_NUM as u16)) as usize;
-        let eo = ((portion[3] + index) % (size_values::PORTION_NUM as u16)) as usize;
+        let ng = (portion[0] as usize + index as usize) % size_values::PORTION_NUM;
+        let chu = (portion[1] as usize + index as usize) % size_values::PORTION_NUM;
+        let yo = (portion[2] as usize + index as usize) % size_values::PORTION_NUM;
+        let eo = (portion[3] as usize + index as usize) % size_values::PORTION_NUM;
 
         let zam = portion[0]
             % prime_arrays::BYTE_PRIMES[(portion[chu] as usize) % size_values::BYTE_PRIME_NUM];
@@ -711,6 +970,14 @@ mod block {
         factor_array_cpy
     }
 
+    pub fn process_message(factor_array: types::ArrTypeRef, padded: &[u16]) -> types::ArrType {
+        let mut factor_array_cpy = tools::copy_array(factor_array);
+        for block in padded.chunks(size_values::BLOCK_NUM) {
+            factor_array_cpy = process_block(&factor_array_cpy, block);
+        }
+        factor_array_cpy
+    }
+
     pub fn process_block(factor_array: types::ArrTypeRef, block: &[u16]) -> types::ArrType {
         let mut factor_array_cpy = tools::copy_array(factor_array);
         for i in (0..size_values::BLOCK_NUM).step_by(size_values::CHUNK_NUM) {
@@ -726,234 +993,1937 @@ mod block {
     }
 }
 
-pub struct PoxDigest {
-    pub sexdigest: String,
-    pub vigdigest: String,
-    pub hexdigest: String,
-    pub tetdigest: String,
-    pub duodigest: String,
-    pub octdigest: String,
-    pub sendigest: String,
-    pub bindigest: String,
-    pub bytes: [u8; 8],
-    pub words: [u16; 4],
-    pub doubles: [u32; 2],
-    pub quad: u64,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RadixError {
+    AlphabetSize(usize),
+    RepeatedChar(char),
+    Length { expected: usize, found: usize },
+    Ragged { width: usize, found: usize },
+    InvalidChar { position: usize, found: char },
+    Overflow { position: usize },
 }
 
-impl std::default::Default for PoxDigest {
-    fn default() -> Self {
-        Self {
-            sexdigest: String::from("unset"),
-            vigdigest: String::from("unset"),
-            hexdigest: String::from("unset"),
-            tetdigest: String::from("unset"),
-            duodigest: String::from("unset"),
-            octdigest: String::from("unset"),
-            sendigest: String::from("unset"),
-            bindigest: String::from("unset"),
-            bytes: [0u8; 8],
-            words: [0u16; 4],
-            doubles: [0u32; 2],
-            quad: 0u64,
+impl std::fmt::Display for RadixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RadixError::AlphabetSize(size) => write!(
+                f,
+                "alphabet has {} chars, expected between {} and {}",
+                size,
+                consts::conversion::RADIX_MIN,
+                consts::conversion::RADIX_MAX
+            ),
+            RadixError::RepeatedChar(c) => {
+                write!(f, "`{}` appears more than once in the alphabet", c)
+            }
+            RadixError::Length { expected, found } => {
+                write!(f, "expected {} digits, found {}", expected, found)
+            }
+            RadixError::Ragged { width, found } => write!(
+                f,
+                "{} digits do not split into words of {} digits",
+                found, width
+            ),
+            RadixError::InvalidChar { position, found } => {
+                write!(
+                    f,
+                    "`{}` at position {} is not in the alphabet",
+                    found, position
+                )
+            }
+            RadixError::Overflow { position } => {
+                write!(
+                    f,
+                    "the word starting at position {} overflows 16 bits",
+                    position
+                )
+            }
         }
     }
 }
 
-impl Clone for PoxDigest {
-    fn clone(&self) -> Self {
-        let mut bytes_clone = [0u8; 8];
-        let mut words_clone = [0u16; 4];
-        let mut doubles_clone = [0u32; 2];
-        let quad_clone = self.quad;
+impl std::error::Error for RadixError {}
 
-        for i in 0..8 {
-            bytes_clone[i] = self.bytes[i];
+/// Positional encoding of each digest word over an alphabet of 2 to 255 unique chars.
+/// Every word takes the fewest digits that can hold 0xFFFF, padded with the zero digit
+/// (the first char of the alphabet), and words are written most significant first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadixEncoding {
+    alphabet: std::borrow::Cow<'static, [char]>,
+    width: usize,
+}
+
+impl RadixEncoding {
+    pub const SEX: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::SEX_CHARS, consts::conversion::SEX_SIZE);
+    pub const VIG: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::VIG_CHARS, consts::conversion::VIG_SIZE);
+    pub const HEX: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::HEX_CHARS, consts::conversion::HEX_SIZE);
+    pub const TET: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::TET_CHARS, consts::conversion::TET_SIZE);
+    pub const DUO: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::DUO_CHARS, consts::conversion::DUO_SIZE);
+    pub const OCT: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::OCT_CHARS, consts::conversion::OCT_SIZE);
+    pub const SEN: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::SEN_CHARS, consts::conversion::SEN_SIZE);
+    pub const BIN: RadixEncoding =
+        RadixEncoding::predefined(&consts::conversion::BIN_CHARS, consts::conversion::BIN_SIZE);
+    pub const BASE36: RadixEncoding = RadixEncoding::predefined(
+        &consts::conversion::BASE36_CHARS,
+        consts::conversion::BASE36_SIZE,
+    );
+    pub const BASE62: RadixEncoding = RadixEncoding::predefined(
+        &consts::conversion::BASE62_CHARS,
+        consts::conversion::BASE62_SIZE,
+    );
+
+    const fn predefined(alphabet: &'static [char], width: usize) -> RadixEncoding {
+        RadixEncoding {
+            alphabet: std::borrow::Cow::Borrowed(alphabet),
+            width,
         }
-        for i in 0..4 {
-            words_clone[i] = self.words[i];
+    }
+
+    /// Checks the alphabet and computes the per-word width.
+    /// Borrows `&'static [char]` tables and takes ownership of a `Vec<char>`.
+    pub fn new<A: Into<std::borrow::Cow<'static, [char]>>>(
+        alphabet: A,
+    ) -> Result<RadixEncoding, RadixError> {
+        let alphabet = alphabet.into();
+        let radix = alphabet.len();
+        if !(consts::conversion::RADIX_MIN..=consts::conversion::RADIX_MAX).contains(&radix) {
+            return Err(RadixError::AlphabetSize(radix));
         }
-        for i in 0..2 {
-            doubles_clone[i] = self.doubles[i];
+        for i in 1..radix {
+            if alphabet[..i].contains(&alphabet[i]) {
+                return Err(RadixError::RepeatedChar(alphabet[i]));
+            }
         }
-        let sexdigest_clone = self.sexdigest.clone();
-        let vigdigest_clone = self.vigdigest.clone();
-        let hexdigest_clone = self.hexdigest.clone();
-        let tetdigest_clone = self.tetdigest.clone();
-        let duodigest_clone = self.duodigest.clone();
-        let octdigest_clone = self.octdigest.clone();
-        let sendigest_clone = self.sendigest.clone();
-        let bindigest_clone = self.bindigest.clone();
 
-        Self {
-            sexdigest: sexdigest_clone,
-            vigdigest: vigdigest_clone,
-            hexdigest: hexdigest_clone,
-            tetdigest: tetdigest_clone,
-            duodigest: duodigest_clone,
-            octdigest: octdigest_clone,
-            sendigest: sendigest_clone,
-            bindigest: bindigest_clone,
-            bytes: bytes_clone,
-            words: words_clone,
-            doubles: doubles_clone,
-            quad: quad_clone,
+        let mut width = 1usize;
+        let mut capacity = radix as u32;
+        while capacity <= consts::bit_values::UINT16_MAX_U32 {
+            capacity *= radix as u32;
+            width += 1;
         }
+
+        Ok(RadixEncoding { alphabet, width })
     }
-}
 
-impl std::fmt::Display for PoxDigest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut _result = write!(f, "Sexdigest: {}\n", &self.sexdigest);
-        _result = write!(f, "Vigdigest: {}\n", &self.vigdigest);
-        _result = write!(f, "Hexdigest: {}\n", &self.hexdigest);
-        _result = write!(f, "Tetdigest: {}\n", &self.tetdigest);
-        _result = write!(f, "Duodigest: {}\n", &self.duodigest);
-        _result = write!(f, "Octdigest: {}\n", &self.octdigest);
-        _result = write!(f, "Sendigest: {}\n", &self.sendigest);
-        _result = write!(f, "Bindigest: {}\n", &self.bindigest);
-        _result = write!(f, "Sexdigest: {}\n", &self.sexdigest);
-        _result = write!(
-            f,
-            "Bytes:    U8[{}, {}, {}, {}, {}, {}, {}, {}]\n",
-            &self.bytes[0],
-            &self.bytes[1],
-            &self.bytes[2],
-            &self.bytes[3],
-            &self.bytes[4],
-            &self.bytes[5],
-            &self.bytes[6],
-            &self.bytes[7]
-        );
-        _result = write!(
-            f,
-            "Words:    U16[{}, {}, {}, {}]\n",
-            &self.words[0], &self.words[1], &self.words[2], &self.words[3]
-        );
-        _result = write!(
-            f,
-            "Doubles:  U32[{}, {}]\n",
-            &self.doubles[0], &self.doubles[1]
-        );
-        write!(f, "Quad:     U64[{}]", &self.quad)
+    pub fn alphabet(&self) -> &[char] {
+        &self.alphabet
     }
-}
 
-impl std::fmt::Debug for PoxDigest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("PoxDigest")
-            .field("Sexdigest", &self.sexdigest)
-            .field("Vigdigest", &self.vigdigest)
-            .field("Hexdigest", &self.hexdigest)
-            .field("Tetdigest", &self.tetdigest)
-            .field("Duodigest", &self.duodigest)
-            .field("Octdigest", &self.octdigest)
-            .field("Sendigest", &self.sendigest)
-            .field("Bindigest", &self.bindigest)
-            .field("Bytes", &self.bytes)
-            .field("Words", &self.words)
-            .field("Doubles", &self.doubles)
-            .field("Quad", &self.quad)
-            .finish()
+    pub fn radix(&self) -> usize {
+        self.alphabet.len()
     }
-}
 
-impl std::fmt::Binary for PoxDigest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.bindigest)
+    /// Number of digits each 16-bit word is written with
+    pub fn width(&self) -> usize {
+        self.width
     }
-}
 
-impl std::fmt::Octal for PoxDigest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.octdigest)
+    pub fn encode_words(&self, words: &[u16]) -> String {
+        let radix = self.alphabet.len() as u32;
+        let mut digest = vec![self.alphabet[0]; self.width * words.len()];
+        for (i, word) in words.iter().enumerate() {
+            let mut decimal = *word as u32;
+            for j in (i * self.width..(i + 1) * self.width).rev() {
+                digest[j] = self.alphabet[(decimal % radix) as usize];
+                decimal /= radix;
+            }
+        }
+        digest.into_iter().collect()
     }
-}
 
-impl std::fmt::UpperHex for PoxDigest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.hexdigest)
+    pub fn decode_words(&self, encoded: &str) -> Result<Vec<u16>, RadixError> {
+        let digits = encoded.chars().collect::<Vec<char>>();
+        if digits.len() % self.width != 0 {
+            return Err(RadixError::Ragged {
+                width: self.width,
+                found: digits.len(),
+            });
+        }
+
+        let radix = self.alphabet.len() as u32;
+        let mut words = Vec::with_capacity(digits.len() / self.width);
+        for (i, chunk) in digits.chunks(self.width).enumerate() {
+            let mut word = 0u32;
+            for (j, digit) in chunk.iter().enumerate() {
+                let value = match self.alphabet.iter().position(|c| c == digit) {
+                    Some(value) => value as u32,
+                    None => {
+                        return Err(RadixError::InvalidChar {
+                            position: i * self.width + j,
+                            found: *digit,
+                        })
+                    }
+                };
+                word = word * radix + value;
+            }
+            if word > consts::bit_values::UINT16_MAX_U32 {
+                return Err(RadixError::Overflow {
+                    position: i * self.width,
+                });
+            }
+            words.push(word as u16);
+        }
+        Ok(words)
+    }
+
+    pub fn encode(&self, digest: &PoxDigest) -> String {
+        self.encode_words(&digest.words)
+    }
+
+    pub fn decode(&self, encoded: &str) -> Result<PoxDigest, RadixError> {
+        let expected = self.width * consts::size_values::PORTION_NUM;
+        let found = encoded.chars().count();
+        if found != expected {
+            return Err(RadixError::Length { expected, found });
+        }
+        let words = self.decode_words(encoded)?;
+        Ok(PoxDigest::from_words([
+            words[0], words[1], words[2], words[3],
+        ]))
     }
 }
 
-impl std::fmt::LowerHex for PoxDigest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.hexdigest.to_lowercase())
+impl std::str::FromStr for RadixEncoding {
+    type Err = RadixError;
+
+    fn from_str(alphabet: &str) -> Result<Self, Self::Err> {
+        RadixEncoding::new(alphabet.chars().collect::<Vec<char>>())
     }
 }
 
-impl std::fmt::Pointer for PoxDigest {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ByteEncodingError {
+    Length { expected: usize, found: usize },
+    InvalidChar { position: usize, found: char },
+    TrailingBits,
+    Overflow,
+    ByteCount(usize),
+    WordCount(usize),
+    UnknownWord { position: usize, found: String },
+}
+
+impl std::fmt::Display for ByteEncodingError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let ptr = self as *const Self;
-        std::fmt::Pointer::fmt(&ptr, f)
+        match self {
+            ByteEncodingError::Length { expected, found } => {
+                write!(f, "expected {} chars, found {}", expected, found)
+            }
+            ByteEncodingError::InvalidChar { position, found } => {
+                write!(f, "unexpected `{}` at position {}", found, position)
+            }
+            ByteEncodingError::TrailingBits => write!(f, "the unused trailing bits are not zero"),
+            ByteEncodingError::Overflow => write!(f, "decodes to more than 8 bytes"),
+            ByteEncodingError::ByteCount(count) => {
+                write!(f, "decodes to {} bytes, expected 8", count)
+            }
+            ByteEncodingError::WordCount(count) => write!(f, "expected 8 words, found {}", count),
+            ByteEncodingError::UnknownWord { position, found } => {
+                write!(f, "`{}` (word {}) is not in the word list", found, position)
+            }
+        }
     }
 }
 
-#[allow(unused_doc_comments)]
-pub fn pox_hash(message: &Vec<u8>) -> PoxDigest {
-    /// Converts the given message into a PoxDigest object
-    /// Parameters:
-    ///     message: Vec<u8>
-    ///
-    /// Returns:
-    ///     PoxDigest
-    ///         PoxDigest.sexdigest: String
-    ///         PoxDigest.vigdigest: String
-    ///         PoxDigest.hexdigest: String
-    ///         PoxDigest.tetdigest: String
-    ///         PoxDigest.duodigest: String
-    ///         PoxDigest.octdigest: String
-    ///         PoxDigest.sendigest: String
-    ///         PoxDigest.bindigest: String
-    ///         PoxDigest.bytes: [u8; 8]
-    ///         PoxDigest.words: [u16; 4]
-    ///         PoxDigest.doubles: [u32, 2]
-    ///         PoxDigest.quad: u64
-    let padded_u16 = operations::bitwise::octopad(message);
-    let mut factor_array: types::ArrType = [
-        consts::initial_primes::PRIME_INIT_A,
-        consts::initial_primes::PRIME_INIT_B,
-        consts::initial_primes::PRIME_INIT_C,
-        consts::initial_primes::PRIME_INIT_D,
+impl std::error::Error for ByteEncodingError {}
+
+/// Standard binary-to-text forms of the 8 bytes in `PoxDigest.bytes`.
+/// Base32 is RFC 4648 section 6, Base64Url is section 5, Base58 uses the Bitcoin
+/// alphabet and keeps leading zero bytes as `1`s. Proquint writes each byte pair as
+/// consonant-vowel-consonant-vowel-consonant, and Mnemonic spells out one word per byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteEncoding {
+    Base32,
+    Base32NoPad,
+    Base64Url,
+    Base64UrlNoPad,
+    Base58,
+    Proquint,
+    Mnemonic,
+}
+
+impl ByteEncoding {
+    pub fn encode_bytes(&self, bytes: &[u8; 8]) -> String {
+        match self {
+            ByteEncoding::Base32 | ByteEncoding::Base32NoPad => {
+                let encoded =
+                    convert::byte_array_to_bit_groups(bytes, consts::conversion::BASE32_CHARS, 5);
+                self.pad(encoded, consts::conversion::BASE32_PADDED_SIZE)
+            }
+            ByteEncoding::Base64Url | ByteEncoding::Base64UrlNoPad => {
+                let encoded = convert::byte_array_to_bit_groups(
+                    bytes,
+                    consts::conversion::BASE64URL_CHARS,
+                    6,
+                );
+                self.pad(encoded, consts::conversion::BASE64_PADDED_SIZE)
+            }
+            ByteEncoding::Base58 => convert::bytes_to_base58(bytes),
+            ByteEncoding::Proquint => convert::byte_array_to_proquint(bytes),
+            ByteEncoding::Mnemonic => convert::byte_array_to_mnemonic(bytes),
+        }
+    }
+
+    pub fn decode_bytes(&self, encoded: &str) -> Result<[u8; 8], ByteEncodingError> {
+        let digits = encoded.chars().collect::<Vec<char>>();
+        match self {
+            ByteEncoding::Base32 | ByteEncoding::Base32NoPad => {
+                let digits = self.unpad(
+                    &digits,
+                    consts::conversion::BASE32_SIZE,
+                    consts::conversion::BASE32_PADDED_SIZE,
+                )?;
+                ByteEncoding::fixed(&convert::bit_groups_to_bytes(
+                    digits,
+                    consts::conversion::BASE32_CHARS,
+                    5,
+                )?)
+            }
+            ByteEncoding::Base64Url | ByteEncoding::Base64UrlNoPad => {
+                let digits = self.unpad(
+                    &digits,
+                    consts::conversion::BASE64_SIZE,
+                    consts::conversion::BASE64_PADDED_SIZE,
+                )?;
+                ByteEncoding::fixed(&convert::bit_groups_to_bytes(
+                    digits,
+                    consts::conversion::BASE64URL_CHARS,
+                    6,
+                )?)
+            }
+            ByteEncoding::Base58 => {
+                let decoded = convert::base58_to_bytes(&digits)?;
+                let zeros = digits
+                    .iter()
+                    .take_while(|c| **c == consts::conversion::BASE58_CHARS[0] as char)
+                    .count();
+                if decoded.len() - zeros > 8 {
+                    return Err(ByteEncodingError::Overflow);
+                }
+                ByteEncoding::fixed(&decoded)
+            }
+            ByteEncoding::Proquint => {
+                if digits.len() != consts::conversion::PROQUINT_SIZE {
+                    return Err(ByteEncodingError::Length {
+                        expected: consts::conversion::PROQUINT_SIZE,
+                        found: digits.len(),
+                    });
+                }
+                convert::proquint_to_byte_array(&digits)
+            }
+            ByteEncoding::Mnemonic => convert::mnemonic_to_byte_array(encoded),
+        }
+    }
+
+    pub fn encode(&self, digest: &PoxDigest) -> String {
+        self.encode_bytes(&digest.bytes)
+    }
+
+    pub fn decode(&self, encoded: &str) -> Result<PoxDigest, ByteEncodingError> {
+        let bytes = self.decode_bytes(encoded)?;
+        Ok(PoxDigest::from_words(convert::byte_array_to_word_array(
+            &bytes,
+        )))
+    }
+
+    fn fixed(decoded: &[u8]) -> Result<[u8; 8], ByteEncodingError> {
+        if decoded.len() != 8 {
+            return Err(ByteEncodingError::ByteCount(decoded.len()));
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(decoded);
+        Ok(bytes)
+    }
+
+    fn is_padded(&self) -> bool {
+        matches!(self, ByteEncoding::Base32 | ByteEncoding::Base64Url)
+    }
+
+    fn pad(&self, mut encoded: String, padded_size: usize) -> String {
+        if self.is_padded() {
+            while encoded.len() < padded_size {
+                encoded.push(consts::conversion::PADDING_CHAR);
+            }
+        }
+        encoded
+    }
+
+    fn unpad<'a>(
+        &self,
+        digits: &'a [char],
+        size: usize,
+        padded_size: usize,
+    ) -> Result<&'a [char], ByteEncodingError> {
+        let expected = if self.is_padded() { padded_size } else { size };
+        if digits.len() != expected {
+            return Err(ByteEncodingError::Length {
+                expected,
+                found: digits.len(),
+            });
+        }
+        if let Some(position) =
+            (size..expected).find(|p| digits[*p] != consts::conversion::PADDING_CHAR)
+        {
+            return Err(ByteEncodingError::InvalidChar {
+                position,
+                found: digits[position],
+            });
+        }
+        Ok(&digits[..size])
+    }
+}
+
+/// Text form used to match typed prefixes in a `DigestIndex`.
+pub trait DigestEncoding {
+    fn encode_digest(&self, digest: &PoxDigest) -> String;
+
+    /// Whether `a` and `A` are different digits in this encoding.
+    fn is_case_sensitive(&self) -> bool;
+}
+
+impl DigestEncoding for RadixEncoding {
+    fn encode_digest(&self, digest: &PoxDigest) -> String {
+        self.encode(digest)
+    }
+
+    fn is_case_sensitive(&self) -> bool {
+        self.alphabet.iter().enumerate().any(|(i, a)| {
+            self.alphabet[i + 1..]
+                .iter()
+                .any(|b| a.eq_ignore_ascii_case(b))
+        })
+    }
+}
+
+impl DigestEncoding for ByteEncoding {
+    fn encode_digest(&self, digest: &PoxDigest) -> String {
+        self.encode(digest)
+    }
+
+    fn is_case_sensitive(&self) -> bool {
+        matches!(
+            self,
+            ByteEncoding::Base64Url | ByteEncoding::Base64UrlNoPad | ByteEncoding::Base58
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LetterCase {
+    AsEncoded,
+    Upper,
+    Lower,
+}
+
+/// Shared rendering for logs and UIs: digits from any `RadixEncoding` (hex by default),
+/// optionally split into groups from the left, case-folded and prefixed.
+/// `DigestFormat::new().group(4, "-")` renders `PoxHash` as `07D0-4B8C-D2E4-7BF3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestFormat {
+    encoding: RadixEncoding,
+    group_size: usize,
+    separator: String,
+    case: LetterCase,
+    prefix: String,
+}
+
+impl DigestFormat {
+    pub fn new() -> DigestFormat {
+        DigestFormat {
+            encoding: RadixEncoding::HEX,
+            group_size: 0,
+            separator: String::new(),
+            case: LetterCase::AsEncoded,
+            prefix: String::new(),
+        }
+    }
+
+    pub fn encoding(mut self, encoding: RadixEncoding) -> DigestFormat {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Puts `separator` between every `size` digits; a size of 0 turns grouping off
+    pub fn group(mut self, size: usize, separator: &str) -> DigestFormat {
+        self.group_size = size;
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Case folding changes the meaning of digits in alphabets with both cases, such as `SEX`
+    pub fn uppercase(mut self) -> DigestFormat {
+        self.case = LetterCase::Upper;
+        self
+    }
+
+    pub fn lowercase(mut self) -> DigestFormat {
+        self.case = LetterCase::Lower;
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &str) -> DigestFormat {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    pub fn format(&self, digest: &PoxDigest) -> String {
+        self.format_words(&digest.words)
+    }
+
+    pub fn format_words(&self, words: &[u16]) -> String {
+        let digits = self.encoding.encode_words(words);
+        let digits = match self.case {
+            LetterCase::AsEncoded => digits,
+            LetterCase::Upper => digits.to_uppercase(),
+            LetterCase::Lower => digits.to_lowercase(),
+        };
+
+        let mut formatted = self.prefix.clone();
+        for (i, digit) in digits.chars().enumerate() {
+            if self.group_size > 0 && i > 0 && i % self.group_size == 0 {
+                formatted.push_str(&self.separator);
+            }
+            formatted.push(digit);
+        }
+        formatted
+    }
+}
+
+impl std::default::Default for DigestFormat {
+    fn default() -> Self {
+        DigestFormat::new()
+    }
+}
+
+pub struct PoxDigest {
+    pub sexdigest: String,
+    pub vigdigest: String,
+    pub hexdigest: String,
+    pub tetdigest: String,
+    pub duodigest: String,
+    pub octdigest: String,
+    pub sendigest: String,
+    pub bindigest: String,
+    pub bytes: [u8; 8],
+    pub words: [u16; 4],
+    pub doubles: [u32; 2],
+    pub quad: u64,
+}
+
+impl PoxDigest {
+    /// Builds every view of the digest from its four words
+    pub fn from_words(words: [u16; 4]) -> PoxDigest {
+        PoxDigest {
+            sexdigest: RadixEncoding::SEX.encode_words(&words),
+            vigdigest: RadixEncoding::VIG.encode_words(&words),
+            hexdigest: RadixEncoding::HEX.encode_words(&words),
+            tetdigest: RadixEncoding::TET.encode_words(&words),
+            duodigest: RadixEncoding::DUO.encode_words(&words),
+            octdigest: RadixEncoding::OCT.encode_words(&words),
+            sendigest: RadixEncoding::SEN.encode_words(&words),
+            bindigest: RadixEncoding::BIN.encode_words(&words),
+            bytes: convert::word_array_to_byte_array(&words),
+            words,
+            doubles: convert::word_array_to_double_array(&words),
+            quad: convert::word_array_to_quad(&words),
+        }
+    }
+
+    // SPEC.md treats everything as little-endian, so the `le` forms are canonical and
+    // equal the `bytes`, `doubles` and `quad` fields. The `be` forms put each word's
+    // high byte first, so `to_be_quad` reads the same as `hexdigest`.
+
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        convert::word_array_to_byte_array(&self.words)
+    }
+
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0u8; 8];
+        for (i, word) in self.words.iter().enumerate() {
+            bytes[i * 2..i * 2 + 2].copy_from_slice(&word.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub fn from_le_bytes(bytes: [u8; 8]) -> PoxDigest {
+        PoxDigest::from_words(convert::byte_array_to_word_array(&bytes))
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 8]) -> PoxDigest {
+        let mut words = [0u16; 4];
+        for (i, word) in words.iter_mut().enumerate() {
+            *word = u16::from_be_bytes([bytes[i * 2], bytes[i * 2 + 1]]);
+        }
+        PoxDigest::from_words(words)
+    }
+
+    pub fn to_le_doubles(&self) -> [u32; 2] {
+        let bytes = self.to_le_bytes();
+        [
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        ]
+    }
+
+    pub fn to_be_doubles(&self) -> [u32; 2] {
+        let bytes = self.to_be_bytes();
+        [
+            u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        ]
+    }
+
+    pub fn from_le_doubles(doubles: [u32; 2]) -> PoxDigest {
+        let (lower, upper) = (doubles[0].to_le_bytes(), doubles[1].to_le_bytes());
+        PoxDigest::from_le_bytes([
+            lower[0], lower[1], lower[2], lower[3], upper[0], upper[1], upper[2], upper[3],
+        ])
+    }
+
+    pub fn from_be_doubles(doubles: [u32; 2]) -> PoxDigest {
+        let (upper, lower) = (doubles[0].to_be_bytes(), doubles[1].to_be_bytes());
+        PoxDigest::from_be_bytes([
+            upper[0], upper[1], upper[2], upper[3], lower[0], lower[1], lower[2], lower[3],
+        ])
+    }
+
+    pub fn to_le_quad(&self) -> u64 {
+        u64::from_le_bytes(self.to_le_bytes())
+    }
+
+    pub fn to_be_quad(&self) -> u64 {
+        u64::from_be_bytes(self.to_be_bytes())
+    }
+
+    pub fn from_le_quad(quad: u64) -> PoxDigest {
+        PoxDigest::from_le_bytes(quad.to_le_bytes())
+    }
+
+    pub fn from_be_quad(quad: u64) -> PoxDigest {
+        PoxDigest::from_be_bytes(quad.to_be_bytes())
+    }
+
+    /// Fixed-size record for binary protocols: a version byte followed by the canonical
+    /// little-endian bytes. Version 1 is the only one defined.
+    pub fn to_wire(&self) -> [u8; 9] {
+        let mut wire = [0u8; 9];
+        wire[0] = consts::wire::WIRE_VERSION;
+        wire[1..].copy_from_slice(&self.to_le_bytes());
+        wire
+    }
+
+    pub fn to_multihash(&self) -> Multihash {
+        Multihash::from_digest(self)
+    }
+
+    pub fn truncate_bits(&self, bits: u32) -> Result<TruncatedDigest, TruncateError> {
+        match bits {
+            8 => Ok(TruncatedDigest::Bits8(self.bytes[0])),
+            16 => Ok(TruncatedDigest::Bits16(self.words[0])),
+            32 => Ok(TruncatedDigest::Bits32(self.doubles[0])),
+            _ => Err(TruncateError::Width(bits)),
+        }
+    }
+
+    pub fn from_wire(wire: &[u8]) -> Result<PoxDigest, WireError> {
+        if wire.len() != consts::wire::WIRE_SIZE {
+            return Err(WireError::Length(wire.len()));
+        }
+        if wire[0] != consts::wire::WIRE_VERSION {
+            return Err(WireError::UnknownVersion(wire[0]));
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&wire[1..]);
+        Ok(PoxDigest::from_le_bytes(bytes))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireError {
+    Length(usize),
+    UnknownVersion(u8),
+}
+
+impl std::fmt::Display for WireError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WireError::Length(found) => write!(
+                f,
+                "a wire digest is {} bytes, found {}",
+                consts::wire::WIRE_SIZE,
+                found
+            ),
+            WireError::UnknownVersion(version) => {
+                write!(f, "unknown wire digest version {}", version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+/// Text forms for a `Multihash`, each introduced by its multibase prefix character.
+/// Base16 and Base32 are lowercase and unpadded, as the multibase table defines them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multibase {
+    Base16,
+    Base32,
+    Base58Btc,
+    Base64Url,
+}
+
+impl Multibase {
+    pub fn prefix(&self) -> char {
+        match self {
+            Multibase::Base16 => consts::multihash::BASE16_PREFIX,
+            Multibase::Base32 => consts::multihash::BASE32_PREFIX,
+            Multibase::Base58Btc => consts::multihash::BASE58BTC_PREFIX,
+            Multibase::Base64Url => consts::multihash::BASE64URL_PREFIX,
+        }
+    }
+
+    pub fn from_prefix(prefix: char) -> Option<Multibase> {
+        [
+            Multibase::Base16,
+            Multibase::Base32,
+            Multibase::Base58Btc,
+            Multibase::Base64Url,
+        ]
+        .iter()
+        .find(|base| base.prefix() == prefix)
+        .copied()
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        let mut encoded = String::new();
+        encoded.push(self.prefix());
+        encoded.push_str(&match self {
+            Multibase::Base16 => {
+                convert::byte_array_to_bit_groups(bytes, consts::multihash::BASE16_CHARS, 4)
+            }
+            Multibase::Base32 => {
+                convert::byte_array_to_bit_groups(bytes, consts::multihash::BASE32_LOWER_CHARS, 5)
+            }
+            Multibase::Base58Btc => convert::bytes_to_base58(bytes),
+            Multibase::Base64Url => {
+                convert::byte_array_to_bit_groups(bytes, consts::conversion::BASE64URL_CHARS, 6)
+            }
+        });
+        encoded
+    }
+
+    /// Decodes text without its prefix character.
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>, ByteEncodingError> {
+        let digits = encoded.chars().collect::<Vec<char>>();
+        match self {
+            Multibase::Base16 => {
+                convert::bit_groups_to_bytes(&digits, consts::multihash::BASE16_CHARS, 4)
+            }
+            Multibase::Base32 => {
+                convert::bit_groups_to_bytes(&digits, consts::multihash::BASE32_LOWER_CHARS, 5)
+            }
+            Multibase::Base58Btc => convert::base58_to_bytes(&digits),
+            Multibase::Base64Url => {
+                convert::bit_groups_to_bytes(&digits, consts::conversion::BASE64URL_CHARS, 6)
+            }
+        }
+    }
+}
+
+/// Self-describing digest: an unsigned-varint algorithm code, a varint digest length and
+/// the digest bytes. Codes sit in the multicodec private-use range; `poxhash-64`
+/// (`0x300a00`) carries the canonical little-endian `PoxDigest` bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Multihash {
+    code: u64,
+    digest: Vec<u8>,
+}
+
+impl Multihash {
+    pub fn new(code: u64, digest: &[u8]) -> Result<Multihash, MultihashError> {
+        let expected = Multihash::digest_size(code)?;
+        if digest.len() != expected {
+            return Err(MultihashError::DigestLength {
+                code,
+                expected,
+                found: digest.len(),
+            });
+        }
+        Ok(Multihash {
+            code,
+            digest: digest.to_vec(),
+        })
+    }
+
+    pub fn from_digest(digest: &PoxDigest) -> Multihash {
+        Multihash {
+            code: consts::multihash::POXHASH_CODE,
+            digest: digest.to_le_bytes().to_vec(),
+        }
+    }
+
+    /// Tags a digest from `pox_hash_words`, which must not be mistaken for a byte hash.
+    pub fn from_words_digest(digest: &PoxDigest) -> Multihash {
+        Multihash {
+            code: consts::multihash::POXHASH_WORDS_CODE,
+            digest: digest.to_le_bytes().to_vec(),
+        }
+    }
+
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    pub fn name(&self) -> &'static str {
+        consts::multihash::CODES
+            .iter()
+            .find(|(code, _, _)| *code == self.code)
+            .map(|(_, _, name)| *name)
+            .unwrap_or_default()
+    }
+
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * consts::multihash::VARINT_MAX_SIZE);
+        convert::u64_to_varint(self.code, &mut bytes);
+        convert::u64_to_varint(self.digest.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Multihash, MultihashError> {
+        let (code, code_size) = convert::varint_to_u64(bytes)?;
+        let (declared, length_size) = convert::varint_to_u64(&bytes[code_size..])?;
+        let digest = &bytes[code_size + length_size..];
+        if digest.len() as u64 != declared {
+            return Err(MultihashError::Truncated {
+                declared,
+                found: digest.len(),
+            });
+        }
+        Multihash::new(code, digest)
+    }
+
+    pub fn to_text(&self, base: Multibase) -> String {
+        base.encode(&self.to_bytes())
+    }
+
+    pub fn from_text(text: &str) -> Result<Multihash, MultihashError> {
+        let prefix = text.chars().next().ok_or(MultihashError::EmptyText)?;
+        let base = Multibase::from_prefix(prefix).ok_or(MultihashError::UnknownBase(prefix))?;
+        let bytes = base
+            .decode(&text[prefix.len_utf8()..])
+            .map_err(MultihashError::Encoding)?;
+        Multihash::from_bytes(&bytes)
+    }
+
+    /// Rebuilds a byte-mode digest. Word-mode and MD tags have their own conversions.
+    pub fn to_pox_digest(&self) -> Result<PoxDigest, MultihashError> {
+        self.to_full_digest(consts::multihash::POXHASH_CODE)
+    }
+
+    /// Rebuilds a digest tagged by `from_words_digest`.
+    pub fn to_words_digest(&self) -> Result<PoxDigest, MultihashError> {
+        self.to_full_digest(consts::multihash::POXHASH_WORDS_CODE)
+    }
+
+    /// Rebuilds a digest tagged by `PoxMdDigest::to_multihash`.
+    pub fn to_md_digest(&self) -> Result<PoxMdDigest, MultihashError> {
+        let digest = self.to_full_digest(consts::multihash::POXHASH_MD_CODE)?;
+        Ok(PoxMdDigest { digest })
+    }
+
+    fn to_full_digest(&self, code: u64) -> Result<PoxDigest, MultihashError> {
+        if self.code != code {
+            return Err(MultihashError::UnexpectedCode {
+                expected: code,
+                found: self.code,
+            });
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.digest);
+        Ok(PoxDigest::from_le_bytes(bytes))
+    }
+
+    fn digest_size(code: u64) -> Result<usize, MultihashError> {
+        consts::multihash::CODES
+            .iter()
+            .find(|(known, _, _)| *known == code)
+            .map(|(_, size, _)| *size)
+            .ok_or(MultihashError::UnknownCode(code))
+    }
+}
+
+impl std::fmt::Display for Multihash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_text(Multibase::Base32))
+    }
+}
+
+impl std::str::FromStr for Multihash {
+    type Err = MultihashError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Multihash::from_text(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultihashError {
+    UnknownCode(u64),
+    UnexpectedCode {
+        expected: u64,
+        found: u64,
+    },
+    DigestLength {
+        code: u64,
+        expected: usize,
+        found: usize,
+    },
+    Truncated {
+        declared: u64,
+        found: usize,
+    },
+    Varint,
+    EmptyText,
+    UnknownBase(char),
+    Encoding(ByteEncodingError),
+}
+
+impl std::fmt::Display for MultihashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultihashError::UnknownCode(code) => write!(f, "unknown multihash code {:#x}", code),
+            MultihashError::UnexpectedCode { expected, found } => {
+                write!(
+                    f,
+                    "expected multihash code {:#x}, found {:#x}",
+                    expected, found
+                )
+            }
+            MultihashError::DigestLength {
+                code,
+                expected,
+                found,
+            } => write!(
+                f,
+                "code {:#x} has {}-byte digests, found {} bytes",
+                code, expected, found
+            ),
+            MultihashError::Truncated { declared, found } => write!(
+                f,
+                "declared a {}-byte digest but {} bytes follow",
+                declared, found
+            ),
+            MultihashError::Varint => write!(f, "malformed unsigned varint"),
+            MultihashError::EmptyText => write!(f, "empty multibase text"),
+            MultihashError::UnknownBase(prefix) => {
+                write!(f, "unknown multibase prefix `{}`", prefix)
+            }
+            MultihashError::Encoding(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for MultihashError {}
+
+/// The leading 8, 16 or 32 bits of a digest, taken from the canonical little-endian
+/// bytes: `Bits8` is `bytes[0]`, `Bits16` is `words[0]` and `Bits32` is `doubles[0]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncatedDigest {
+    Bits8(u8),
+    Bits16(u16),
+    Bits32(u32),
+}
+
+impl TruncatedDigest {
+    pub fn bits(&self) -> u32 {
+        match self {
+            TruncatedDigest::Bits8(_) => 8,
+            TruncatedDigest::Bits16(_) => 16,
+            TruncatedDigest::Bits32(_) => 32,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        match self {
+            TruncatedDigest::Bits8(value) => *value as u32,
+            TruncatedDigest::Bits16(value) => *value as u32,
+            TruncatedDigest::Bits32(value) => *value,
+        }
+    }
+
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        self.value().to_le_bytes()[..self.bits() as usize / 8].to_vec()
+    }
+
+    pub fn to_multihash(&self) -> Multihash {
+        let code = match self {
+            TruncatedDigest::Bits8(_) => consts::multihash::POXHASH_8_CODE,
+            TruncatedDigest::Bits16(_) => consts::multihash::POXHASH_16_CODE,
+            TruncatedDigest::Bits32(_) => consts::multihash::POXHASH_32_CODE,
+        };
+        Multihash {
+            code,
+            digest: self.to_le_bytes(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncateError {
+    Width(u32),
+}
+
+impl std::fmt::Display for TruncateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TruncateError::Width(bits) => {
+                write!(f, "digests truncate to 8, 16 or 32 bits, not {}", bits)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TruncateError {}
+
+/// A set of digests that resolves typed prefixes to a single entry, the way git resolves
+/// abbreviated commit ids. Prefixes are matched against the text from any
+/// `DigestEncoding`, ignoring case when the encoding does.
+#[derive(Debug, Default)]
+pub struct DigestIndex {
+    digests: Vec<PoxDigest>,
+    positions: std::collections::HashMap<[u16; 4], usize>,
+}
+
+impl DigestIndex {
+    pub fn new() -> DigestIndex {
+        DigestIndex::default()
+    }
+
+    /// Adds a digest, returning `false` if it was already present.
+    pub fn insert(&mut self, digest: PoxDigest) -> bool {
+        if self.contains(&digest) {
+            return false;
+        }
+        self.positions.insert(digest.words, self.digests.len());
+        self.digests.push(digest);
+        true
+    }
+
+    pub fn contains(&self, digest: &PoxDigest) -> bool {
+        self.positions.contains_key(&digest.words)
+    }
+
+    pub fn len(&self) -> usize {
+        self.digests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.digests.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, PoxDigest> {
+        self.digests.iter()
+    }
+
+    /// Encodes and sorts every entry once. Build this when making many queries in the
+    /// same encoding; the methods below build one for each call.
+    pub fn encoded<E: DigestEncoding>(&self, encoding: &E) -> EncodedIndex<'_> {
+        let case_sensitive = encoding.is_case_sensitive();
+        let mut entries = self
+            .digests
+            .iter()
+            .enumerate()
+            .map(|(position, digest)| {
+                let encoded = encoding.encode_digest(digest);
+                EncodedEntry {
+                    folded: EncodedIndex::fold(&encoded, case_sensitive),
+                    encoded,
+                    position,
+                }
+            })
+            .collect::<Vec<EncodedEntry>>();
+        entries.sort_unstable_by(|a, b| a.folded.cmp(&b.folded));
+        let mut ranks = vec![0; entries.len()];
+        for (rank, entry) in entries.iter().enumerate() {
+            ranks[entry.position] = rank;
+        }
+        EncodedIndex {
+            index: self,
+            case_sensitive,
+            entries,
+            ranks,
+        }
+    }
+
+    /// Finds the one digest whose encoding starts with `prefix`. A complete encoding
+    /// always resolves to its own digest, even if it also prefixes a longer one.
+    pub fn resolve<E: DigestEncoding>(
+        &self,
+        prefix: &str,
+        encoding: &E,
+    ) -> Result<&PoxDigest, PrefixError> {
+        self.encoded(encoding).resolve(prefix)
+    }
+
+    /// Number of leading characters of `digest`'s encoding that no other entry shares,
+    /// or `None` if the digest is not in the index.
+    pub fn unique_prefix_len<E: DigestEncoding>(
+        &self,
+        digest: &PoxDigest,
+        encoding: &E,
+    ) -> Option<usize> {
+        self.encoded(encoding).unique_prefix_len(digest)
+    }
+
+    /// The shortest unique prefix of every entry, in insertion order.
+    pub fn unique_prefixes<E: DigestEncoding>(&self, encoding: &E) -> Vec<String> {
+        self.encoded(encoding).unique_prefixes()
+    }
+}
+
+/// The entries of a `DigestIndex` in one encoding, sorted by their case-folded text. The
+/// entries a prefix matches are a contiguous run, and an entry shares its longest prefix
+/// with one of its neighbours, so queries are binary searches.
+#[derive(Debug)]
+pub struct EncodedIndex<'a> {
+    index: &'a DigestIndex,
+    case_sensitive: bool,
+    entries: Vec<EncodedEntry>,
+    ranks: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct EncodedEntry {
+    folded: String,
+    encoded: String,
+    position: usize,
+}
+
+impl<'a> EncodedIndex<'a> {
+    pub fn resolve(&self, prefix: &str) -> Result<&'a PoxDigest, PrefixError> {
+        if prefix.is_empty() {
+            return Err(PrefixError::Empty);
+        }
+        let prefix = EncodedIndex::fold(prefix, self.case_sensitive);
+        let start = self.entries.partition_point(|entry| entry.folded < prefix);
+        let matches = self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.folded.starts_with(&prefix))
+            .collect::<Vec<&EncodedEntry>>();
+        match matches.first() {
+            None => Err(PrefixError::NotFound),
+            // A complete encoding sorts before every longer one it prefixes
+            Some(first) if matches.len() == 1 || first.folded == prefix => {
+                Ok(&self.index.digests[first.position])
+            }
+            Some(_) => {
+                let mut candidates = matches
+                    .iter()
+                    .map(|entry| entry.encoded.clone())
+                    .collect::<Vec<String>>();
+                candidates.sort();
+                Err(PrefixError::Ambiguous { candidates })
+            }
+        }
+    }
+
+    pub fn unique_prefix_len(&self, digest: &PoxDigest) -> Option<usize> {
+        let position = *self.index.positions.get(&digest.words)?;
+        Some(self.unique_len(self.ranks[position]))
+    }
+
+    /// The shortest unique prefix of every entry, in insertion order.
+    pub fn unique_prefixes(&self) -> Vec<String> {
+        self.ranks
+            .iter()
+            .map(|rank| {
+                let length = self.unique_len(*rank);
+                self.entries[*rank].encoded.chars().take(length).collect()
+            })
+            .collect()
+    }
+
+    fn unique_len(&self, rank: usize) -> usize {
+        let target = &self.entries[rank].folded;
+        let shared = |other: &EncodedEntry| {
+            target
+                .chars()
+                .zip(other.folded.chars())
+                .take_while(|(a, b)| a == b)
+                .count()
+        };
+        let before = rank.checked_sub(1).map_or(0, |i| shared(&self.entries[i]));
+        let after = self.entries.get(rank + 1).map_or(0, shared);
+        std::cmp::min(std::cmp::max(before, after) + 1, target.chars().count())
+    }
+
+    fn fold(text: &str, case_sensitive: bool) -> String {
+        if case_sensitive {
+            text.to_string()
+        } else {
+            text.to_ascii_lowercase()
+        }
+    }
+}
+
+impl std::iter::FromIterator<PoxDigest> for DigestIndex {
+    fn from_iter<I: IntoIterator<Item = PoxDigest>>(digests: I) -> Self {
+        let mut index = DigestIndex::new();
+        for digest in digests {
+            index.insert(digest);
+        }
+        index
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrefixError {
+    Empty,
+    NotFound,
+    Ambiguous { candidates: Vec<String> },
+}
+
+impl std::fmt::Display for PrefixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrefixError::Empty => write!(f, "empty digest prefix"),
+            PrefixError::NotFound => write!(f, "no digest matches the prefix"),
+            PrefixError::Ambiguous { candidates } => write!(
+                f,
+                "ambiguous digest prefix, candidates are {}",
+                candidates.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PrefixError {}
+
+impl std::default::Default for PoxDigest {
+    fn default() -> Self {
+        Self {
+            sexdigest: String::from("unset"),
+            vigdigest: String::from("unset"),
+            hexdigest: String::from("unset"),
+            tetdigest: String::from("unset"),
+            duodigest: String::from("unset"),
+            octdigest: String::from("unset"),
+            sendigest: String::from("unset"),
+            bindigest: String::from("unset"),
+            bytes: [0u8; 8],
+            words: [0u16; 4],
+            doubles: [0u32; 2],
+            quad: 0u64,
+        }
+    }
+}
+
+impl Clone for PoxDigest {
+    fn clone(&self) -> Self {
+        let mut bytes_clone = [0u8; 8];
+        let mut words_clone = [0u16; 4];
+        let mut doubles_clone = [0u32; 2];
+        let quad_clone = self.quad;
+
+        for i in 0..8 {
+            bytes_clone[i] = self.bytes[i];
+        }
+        for i in 0..4 {
+            words_clone[i] = self.words[i];
+        }
+        for i in 0..2 {
+            doubles_clone[i] = self.doubles[i];
+        }
+        let sexdigest_clone = self.sexdigest.clone();
+        let vigdigest_clone = self.vigdigest.clone();
+        let hexdigest_clone = self.hexdigest.clone();
+        let tetdigest_clone = self.tetdigest.clone();
+        let duodigest_clone = self.duodigest.clone();
+        let octdigest_clone = self.octdigest.clone();
+        let sendigest_clone = self.sendigest.clone();
+        let bindigest_clone = self.bindigest.clone();
+
+        Self {
+            sexdigest: sexdigest_clone,
+            vigdigest: vigdigest_clone,
+            hexdigest: hexdigest_clone,
+            tetdigest: tetdigest_clone,
+            duodigest: duodigest_clone,
+            octdigest: octdigest_clone,
+            sendigest: sendigest_clone,
+            bindigest: bindigest_clone,
+            bytes: bytes_clone,
+            words: words_clone,
+            doubles: doubles_clone,
+            quad: quad_clone,
+        }
+    }
+}
+
+impl std::fmt::Display for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Sexdigest: {}", &self.sexdigest)?;
+        writeln!(f, "Vigdigest: {}", &self.vigdigest)?;
+        writeln!(f, "Hexdigest: {}", &self.hexdigest)?;
+        writeln!(f, "Tetdigest: {}", &self.tetdigest)?;
+        writeln!(f, "Duodigest: {}", &self.duodigest)?;
+        writeln!(f, "Octdigest: {}", &self.octdigest)?;
+        writeln!(f, "Sendigest: {}", &self.sendigest)?;
+        writeln!(f, "Bindigest: {}", &self.bindigest)?;
+        writeln!(
+            f,
+            "Bytes:    U8[{}, {}, {}, {}, {}, {}, {}, {}]",
+            &self.bytes[0],
+            &self.bytes[1],
+            &self.bytes[2],
+            &self.bytes[3],
+            &self.bytes[4],
+            &self.bytes[5],
+            &self.bytes[6],
+            &self.bytes[7]
+        )?;
+        writeln!(
+            f,
+            "Words:    U16[{}, {}, {}, {}]",
+            &self.words[0], &self.words[1], &self.words[2], &self.words[3]
+        )?;
+        writeln!(
+            f,
+            "Doubles:  U32[{}, {}]",
+            &self.doubles[0], &self.doubles[1]
+        )?;
+        write!(f, "Quad:     U64[{}]", &self.quad)
+    }
+}
+
+impl std::fmt::Debug for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PoxDigest")
+            .field("Sexdigest", &self.sexdigest)
+            .field("Vigdigest", &self.vigdigest)
+            .field("Hexdigest", &self.hexdigest)
+            .field("Tetdigest", &self.tetdigest)
+            .field("Duodigest", &self.duodigest)
+            .field("Octdigest", &self.octdigest)
+            .field("Sendigest", &self.sendigest)
+            .field("Bindigest", &self.bindigest)
+            .field("Bytes", &self.bytes)
+            .field("Words", &self.words)
+            .field("Doubles", &self.doubles)
+            .field("Quad", &self.quad)
+            .finish()
+    }
+}
+
+// Precision truncates to the leading digits, `#` adds the usual prefix, and width,
+// fill, alignment and `0` padding behave as they do for integers. A digest has no sign,
+// so this pads by hand instead of through `pad_integral`, which would honour `+`.
+fn pad_digest(f: &mut std::fmt::Formatter<'_>, prefix: &str, digits: &str) -> std::fmt::Result {
+    let digits = match f.precision() {
+        Some(precision) if precision < digits.len() => &digits[..precision],
+        _ => digits,
+    };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(prefix.len() + digits.len()));
+    if f.sign_aware_zero_pad() {
+        return write!(f, "{}{}{}", prefix, "0".repeat(padding), digits);
+    }
+    let (before, after) = match f.align() {
+        Some(std::fmt::Alignment::Left) => (0, padding),
+        Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill().to_string();
+    write!(
+        f,
+        "{}{}{}{}",
+        fill.repeat(before),
+        prefix,
+        digits,
+        fill.repeat(after)
+    )
+}
+
+impl std::fmt::Binary for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        pad_digest(f, "0b", &self.bindigest)
+    }
+}
+
+impl std::fmt::Octal for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        pad_digest(f, "0o", &self.octdigest)
+    }
+}
+
+impl std::fmt::UpperHex for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        pad_digest(f, "0x", &self.hexdigest)
+    }
+}
+
+impl std::fmt::LowerHex for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        pad_digest(f, "0x", &self.hexdigest.to_lowercase())
+    }
+}
+
+impl std::fmt::Pointer for PoxDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ptr = self as *const Self;
+        std::fmt::Pointer::fmt(&ptr, f)
+    }
+}
+
+/// Character sets `hash_text` can turn a string into. UTF-16 is written without a byte
+/// order mark, so `Utf16Le` gives the bytes of a JavaScript string's code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+/// What Latin-1 does with a character above U+00FF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unmappable {
+    Reject,
+    Replace(u8),
+}
+
+/// How a string becomes the message bytes: a charset, the Latin-1 fallback for
+/// unmappable characters and whether CRLF line endings are folded to LF first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEncoding {
+    charset: Charset,
+    unmappable: Unmappable,
+    normalize_newlines: bool,
+}
+
+impl TextEncoding {
+    pub const UTF8: TextEncoding = TextEncoding::new(Charset::Utf8);
+    pub const UTF16LE: TextEncoding = TextEncoding::new(Charset::Utf16Le);
+    pub const UTF16BE: TextEncoding = TextEncoding::new(Charset::Utf16Be);
+    pub const LATIN1: TextEncoding = TextEncoding::new(Charset::Latin1);
+
+    pub const fn new(charset: Charset) -> TextEncoding {
+        TextEncoding {
+            charset,
+            unmappable: Unmappable::Reject,
+            normalize_newlines: false,
+        }
+    }
+
+    pub fn charset(&self) -> Charset {
+        self.charset
+    }
+
+    /// Only Latin-1 has unmappable characters; the other charsets ignore this
+    pub fn unmappable(mut self, unmappable: Unmappable) -> TextEncoding {
+        self.unmappable = unmappable;
+        self
+    }
+
+    pub fn normalize_newlines(mut self) -> TextEncoding {
+        self.normalize_newlines = true;
+        self
+    }
+
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>, TextEncodingError> {
+        let normalized;
+        let folded = if self.normalize_newlines && self.charset != Charset::Latin1 {
+            normalized = text.replace(consts::text::CRLF, consts::text::LF);
+            normalized.as_str()
+        } else {
+            text
+        };
+        match self.charset {
+            Charset::Utf8 => Ok(folded.as_bytes().to_vec()),
+            Charset::Utf16Le => Ok(folded.encode_utf16().flat_map(u16::to_le_bytes).collect()),
+            Charset::Utf16Be => Ok(folded.encode_utf16().flat_map(u16::to_be_bytes).collect()),
+            Charset::Latin1 => self.encode_latin1(text),
+        }
+    }
+
+    // CRLF is folded while encoding, so an unmappable position counts characters of `text`
+    // as given rather than of the folded string.
+    fn encode_latin1(&self, text: &str) -> Result<Vec<u8>, TextEncodingError> {
+        let mut bytes = Vec::with_capacity(text.len());
+        for (position, (offset, found)) in text.char_indices().enumerate() {
+            if self.normalize_newlines && text[offset..].starts_with(consts::text::CRLF) {
+                continue;
+            }
+            if found <= consts::text::LATIN1_MAX {
+                bytes.push(found as u8);
+                continue;
+            }
+            match self.unmappable {
+                Unmappable::Replace(byte) => bytes.push(byte),
+                Unmappable::Reject => {
+                    return Err(TextEncodingError::Unmappable { position, found })
+                }
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncodingError {
+    Unmappable { position: usize, found: char },
+}
+
+impl std::fmt::Display for TextEncodingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextEncodingError::Unmappable { position, found } => write!(
+                f,
+                "`{}` (U+{:04X}) at position {} has no Latin-1 byte",
+                found, *found as u32, position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TextEncodingError {}
+
+#[allow(unused_doc_comments)]
+pub fn pox_hash(message: &Vec<u8>) -> PoxDigest {
+    /// Converts the given message into a PoxDigest object
+    /// Parameters:
+    ///     message: Vec<u8>
+    ///
+    /// Returns:
+    ///     PoxDigest
+    ///         PoxDigest.sexdigest: String
+    ///         PoxDigest.vigdigest: String
+    ///         PoxDigest.hexdigest: String
+    ///         PoxDigest.tetdigest: String
+    ///         PoxDigest.duodigest: String
+    ///         PoxDigest.octdigest: String
+    ///         PoxDigest.sendigest: String
+    ///         PoxDigest.bindigest: String
+    ///         PoxDigest.bytes: [u8; 8]
+    ///         PoxDigest.words: [u16; 4]
+    ///         PoxDigest.doubles: [u32, 2]
+    ///         PoxDigest.quad: u64
+    let padded_u16 = operations::bitwise::octopad(message);
+    let factor_array: types::ArrType = [
+        consts::initial_primes::PRIME_INIT_A,
+        consts::initial_primes::PRIME_INIT_B,
+        consts::initial_primes::PRIME_INIT_C,
+        consts::initial_primes::PRIME_INIT_D,
     ];
 
-    for i in (0..padded_u16.len()).step_by(consts::size_values::BLOCK_NUM) {
-        factor_array = block::process_block(
-            &factor_array,
-            &padded_u16[i..i + consts::size_values::BLOCK_NUM],
-        );
-    }
-
-    let sexdigest = convert::word_array_to_sex_digest(&factor_array);
-    let vigdigest = convert::word_array_to_vig_digest(&factor_array);
-    let hexdigest = convert::word_array_to_hex_digest(&factor_array);
-    let tetdigest = convert::word_array_to_tet_digest(&factor_array);
-    let duodigest = convert::word_array_to_duo_digest(&factor_array);
-    let octdigest = convert::word_array_to_oct_digest(&factor_array);
-    let sendigest = convert::word_array_to_sen_digest(&factor_array);
-    let bindigest = convert::word_array_to_bin_digest(&factor_array);
-    let bytes = convert::word_array_to_byte_array(&factor_array);
-    let words: [u16; 4] = [
-        factor_array[0],
-        factor_array[1],
-        factor_array[2],
-        factor_array[3],
+    PoxDigest::from_words(block::process_message(&factor_array, &padded_u16))
+}
+
+/// Incremental `pox_hash`. Blocks are compressed as they fill up; only the unfinished
+/// block and the first bytes of the message, which `octopad` draws its padding from,
+/// are kept in memory.
+#[derive(Debug, Clone)]
+pub struct PoxHasher {
+    factor_array: [u16; 4],
+    head: Vec<u8>,
+    pending: Vec<u8>,
+    message_len: usize,
+}
+
+impl PoxHasher {
+    pub fn new() -> PoxHasher {
+        PoxHasher {
+            factor_array: [
+                consts::initial_primes::PRIME_INIT_A,
+                consts::initial_primes::PRIME_INIT_B,
+                consts::initial_primes::PRIME_INIT_C,
+                consts::initial_primes::PRIME_INIT_D,
+            ],
+            head: Vec::new(),
+            pending: Vec::with_capacity(consts::size_values::BLOCK_NUM),
+            message_len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        let head_room = consts::stream::OCTOPAD_REACH - self.head.len();
+        self.head
+            .extend_from_slice(&bytes[..std::cmp::min(head_room, bytes.len())]);
+        self.message_len += bytes.len();
+        if !self.pending.is_empty() {
+            let take = std::cmp::min(
+                consts::size_values::BLOCK_NUM - self.pending.len(),
+                bytes.len(),
+            );
+            self.pending.extend_from_slice(&bytes[..take]);
+            bytes = &bytes[take..];
+            if self.pending.len() < consts::size_values::BLOCK_NUM {
+                return;
+            }
+            let pending = std::mem::take(&mut self.pending);
+            self.compress(&pending);
+        }
+        let mut blocks = bytes.chunks_exact(consts::size_values::BLOCK_NUM);
+        for block in &mut blocks {
+            self.compress(block);
+        }
+        self.pending.extend_from_slice(blocks.remainder());
+    }
+
+    pub fn finalize(self) -> PoxDigest {
+        let padded_u16 =
+            operations::bitwise::octopad_tail(&self.head, &self.pending, self.message_len);
+        PoxDigest::from_words(block::process_message(&self.factor_array, &padded_u16))
+    }
+
+    fn compress(&mut self, block: &[u8]) {
+        let words = block.iter().map(|b| *b as u16).collect::<Vec<u16>>();
+        self.factor_array = block::process_block(&self.factor_array, &words);
+    }
+}
+
+impl std::default::Default for PoxHasher {
+    fn default() -> Self {
+        PoxHasher::new()
+    }
+}
+
+impl std::io::Write for PoxHasher {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.update(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Hashes 16-bit message words directly instead of widening bytes. Words are padded
+/// with a single `0x8000` word and then zeros up to a whole block, and hashing starts
+/// from the bitwise complement of the byte-mode factors. The blocks go through the
+/// same compression function as `pox_hash`. The two modes are domain-separated by a
+/// distinct IV and padding rule, even for words that all fit in a byte.
+pub fn pox_hash_words(message: &[u16]) -> PoxDigest {
+    let padded_u16 = operations::bitwise::wordpad(message);
+    let factor_array: types::ArrType = [
+        consts::words::WORDS_INIT_A,
+        consts::words::WORDS_INIT_B,
+        consts::words::WORDS_INIT_C,
+        consts::words::WORDS_INIT_D,
     ];
-    let doubles = convert::word_array_to_double_array(&factor_array);
-    let quad = convert::word_array_to_quad(&factor_array);
-
-    PoxDigest {
-        sexdigest,
-        vigdigest,
-        hexdigest,
-        tetdigest,
-        duodigest,
-        octdigest,
-        sendigest,
-        bindigest,
-        bytes,
-        words,
-        doubles,
-        quad,
+
+    PoxDigest::from_words(block::process_message(&factor_array, &padded_u16))
+}
+
+/// Hashes `text` after encoding it with `encoding`, so that every port hashing the same
+/// string with the same encoding gets the same digest.
+pub fn hash_text(text: &str, encoding: TextEncoding) -> Result<PoxDigest, TextEncodingError> {
+    Ok(pox_hash(&encoding.encode(text)?))
+}
+
+/// Digest of PoxHash-MD. It is a distinct type so that it cannot be stored or compared as
+/// a spec `PoxDigest` by accident; `digest` gives the usual views.
+#[derive(Debug, Clone)]
+pub struct PoxMdDigest {
+    digest: PoxDigest,
+}
+
+impl PoxMdDigest {
+    pub fn digest(&self) -> &PoxDigest {
+        &self.digest
+    }
+
+    pub fn into_digest(self) -> PoxDigest {
+        self.digest
+    }
+
+    pub fn to_multihash(&self) -> Multihash {
+        Multihash {
+            code: consts::multihash::POXHASH_MD_CODE,
+            digest: self.digest.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+impl std::fmt::Display for PoxMdDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.digest, f)
+    }
+}
+
+/// Incremental PoxHash-MD. Each block is compressed as soon as 64 bytes are available,
+/// so only the unfinished block is ever held in memory.
+#[derive(Debug, Clone)]
+pub struct PoxMdHasher {
+    factor_array: [u16; 4],
+    pending: Vec<u8>,
+    message_len: u64,
+}
+
+impl PoxMdHasher {
+    pub fn new() -> PoxMdHasher {
+        PoxMdHasher {
+            factor_array: [
+                consts::md::MD_INIT_A,
+                consts::md::MD_INIT_B,
+                consts::md::MD_INIT_C,
+                consts::md::MD_INIT_D,
+            ],
+            pending: Vec::with_capacity(consts::size_values::BLOCK_NUM),
+            message_len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        self.message_len = self.message_len.wrapping_add(bytes.len() as u64);
+        if !self.pending.is_empty() {
+            let take = std::cmp::min(
+                consts::size_values::BLOCK_NUM - self.pending.len(),
+                bytes.len(),
+            );
+            self.pending.extend_from_slice(&bytes[..take]);
+            bytes = &bytes[take..];
+            if self.pending.len() < consts::size_values::BLOCK_NUM {
+                return;
+            }
+            let pending = std::mem::take(&mut self.pending);
+            self.compress(&pending);
+        }
+        let mut blocks = bytes.chunks_exact(consts::size_values::BLOCK_NUM);
+        for block in &mut blocks {
+            self.compress(block);
+        }
+        self.pending.extend_from_slice(blocks.remainder());
+    }
+
+    pub fn finalize(self) -> PoxMdDigest {
+        let padded_u16 = operations::bitwise::mdpad(&self.pending, self.message_len);
+        PoxMdDigest {
+            digest: PoxDigest::from_words(block::process_message(&self.factor_array, &padded_u16)),
+        }
+    }
+
+    fn compress(&mut self, block: &[u8]) {
+        let words = block.iter().map(|b| *b as u16).collect::<Vec<u16>>();
+        self.factor_array = block::process_block(&self.factor_array, &words);
+    }
+}
+
+impl std::default::Default for PoxMdHasher {
+    fn default() -> Self {
+        PoxMdHasher::new()
+    }
+}
+
+impl std::io::Write for PoxMdHasher {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.update(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// PoxHash-MD: the spec's compression function with Merkle-Damgard padding instead of
+/// `octopad`. Each byte is widened to a message word as usual; then a `0x80` word, zero
+/// words up to 56 mod 64, and the message length in bytes as 8 little-endian byte words.
+/// It is defined for the empty message and can be computed in one pass.
+pub fn pox_hash_md(message: &[u8]) -> PoxMdDigest {
+    let mut hasher = PoxMdHasher::new();
+    hasher.update(message);
+    hasher.finalize()
+}
+
+/// A value with a canonical byte encoding, so that equal values get equal digests and
+/// unequal values almost never do. Every encoding is self-delimiting, which makes the
+/// encodings of consecutive values safe to concatenate: `("ab", "c")` and `("a", "bc")`
+/// encode differently. Derive it with `poxh_derive` for structs and enums.
+pub trait PoxHashable {
+    fn pox_encode(&self, out: &mut Vec<u8>);
+
+    fn pox_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.pox_encode(&mut out);
+        out
+    }
+
+    fn pox_digest(&self) -> PoxDigest {
+        pox_hash(&self.pox_bytes())
+    }
+}
+
+macro_rules! hashable_le_bytes {
+    ($($num_type:ty),*) => {
+        $(
+            impl PoxHashable for $num_type {
+                fn pox_encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+hashable_le_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl PoxHashable for usize {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        (*self as u64).pox_encode(out);
+    }
+}
+
+impl PoxHashable for isize {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        (*self as i64).pox_encode(out);
+    }
+}
+
+impl PoxHashable for bool {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        out.push(if *self {
+            consts::hashable::TRUE_BYTE
+        } else {
+            consts::hashable::FALSE_BYTE
+        });
+    }
+}
+
+impl PoxHashable for char {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        (*self as u32).pox_encode(out);
+    }
+}
+
+impl PoxHashable for str {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        self.len().pox_encode(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl PoxHashable for String {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        self.as_str().pox_encode(out);
+    }
+}
+
+impl<T: PoxHashable> PoxHashable for [T] {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        self.len().pox_encode(out);
+        for item in self {
+            item.pox_encode(out);
+        }
+    }
+}
+
+impl<T: PoxHashable, const N: usize> PoxHashable for [T; N] {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        self[..].pox_encode(out);
+    }
+}
+
+impl<T: PoxHashable> PoxHashable for Vec<T> {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        self[..].pox_encode(out);
+    }
+}
+
+impl<T: PoxHashable> PoxHashable for Option<T> {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(consts::hashable::NONE_TAG),
+            Some(value) => {
+                out.push(consts::hashable::SOME_TAG);
+                value.pox_encode(out);
+            }
+        }
+    }
+}
+
+impl<T: PoxHashable + ?Sized> PoxHashable for &T {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        (**self).pox_encode(out);
+    }
+}
+
+impl<T: PoxHashable + ?Sized> PoxHashable for Box<T> {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        (**self).pox_encode(out);
+    }
+}
+
+impl PoxHashable for () {
+    fn pox_encode(&self, _out: &mut Vec<u8>) {}
+}
+
+impl<T: ?Sized> PoxHashable for std::marker::PhantomData<T> {
+    fn pox_encode(&self, _out: &mut Vec<u8>) {}
+}
+
+macro_rules! hashable_tuple {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(
+            impl<$($name: PoxHashable),+> PoxHashable for ($($name,)+) {
+                fn pox_encode(&self, out: &mut Vec<u8>) {
+                    $(self.$index.pox_encode(out);)+
+                }
+            }
+        )*
+    };
+}
+
+hashable_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+);
+
+/// Maps are encoded as their length, then their entries in key order, so a `HashMap`
+/// and a `BTreeMap` holding the same entries encode alike.
+fn encode_map_entries<'a, K, V, I>(entries: I, out: &mut Vec<u8>)
+where
+    K: PoxHashable + Ord + 'a,
+    V: PoxHashable + 'a,
+    I: Iterator<Item = (&'a K, &'a V)>,
+{
+    let mut sorted = entries.collect::<Vec<(&K, &V)>>();
+    sorted.sort_by(|a, b| a.0.cmp(b.0));
+    sorted.len().pox_encode(out);
+    for (key, value) in sorted {
+        key.pox_encode(out);
+        value.pox_encode(out);
+    }
+}
+
+impl<K: PoxHashable + Ord, V: PoxHashable> PoxHashable for std::collections::BTreeMap<K, V> {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        encode_map_entries(self.iter(), out);
+    }
+}
+
+impl<K: PoxHashable + Ord, V: PoxHashable, S> PoxHashable for std::collections::HashMap<K, V, S> {
+    fn pox_encode(&self, out: &mut Vec<u8>) {
+        encode_map_entries(self.iter(), out);
     }
 }
@@ -0,0 +1,379 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                     PoxHashable derive macro for libpoxh.rs                     //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+const TRAIT_PATH: &'static str = "::libpoxh::PoxHashable";
+const BINDING_PREFIX: &'static str = "__poxh_field_";
+const RAW_PREFIX: &'static str = "r#";
+
+enum Fields {
+    Named(Vec<String>),
+    Unnamed(usize),
+    Unit,
+}
+
+struct Variant {
+    name: String,
+    fields: Fields,
+}
+
+enum Body {
+    Struct(Fields),
+    Enum(Vec<Variant>),
+}
+
+struct Item {
+    name: String,
+    params: Vec<Vec<TokenTree>>,
+    where_clause: Vec<TokenTree>,
+    body: Body,
+}
+
+/// Derives `libpoxh::PoxHashable`. A struct is encoded as its field count, then each field's
+/// name and value in declaration order; a tuple struct leaves out the names. An enum is
+/// encoded as the name of its variant, then the variant's fields the same way. Every type
+/// parameter must be `PoxHashable` as well.
+#[proc_macro_derive(PoxHashable)]
+pub fn derive_pox_hashable(input: TokenStream) -> TokenStream {
+    let code = match parse_item(input) {
+        Ok(item) => expand(&item),
+        Err(message) => format!("compile_error!({:?});", message),
+    };
+    code.parse()
+        .expect("derived PoxHashable impl is valid Rust")
+}
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    match token {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == ch,
+        _ => false,
+    }
+}
+
+fn ident_at(tokens: &[TokenTree], pos: usize) -> Option<String> {
+    match tokens.get(pos) {
+        Some(TokenTree::Ident(ident)) => Some(ident.to_string()),
+        _ => None,
+    }
+}
+
+fn to_source(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
+
+fn field_name(ident: &str) -> &str {
+    ident.trim_start_matches(RAW_PREFIX)
+}
+
+fn skip_attributes_and_visibility(tokens: &[TokenTree], mut pos: usize) -> usize {
+    loop {
+        if is_punct(tokens.get(pos), '#') {
+            pos += 2;
+        } else if ident_at(tokens, pos).is_some_and(|ident| ident == "pub") {
+            pos += 1;
+            if let Some(TokenTree::Group(group)) = tokens.get(pos) {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    pos += 1;
+                }
+            }
+        } else {
+            return pos;
+        }
+    }
+}
+
+/// Splits `tokens` at the commas that are not inside angle brackets. Commas inside
+/// parentheses, brackets and braces are already hidden in their groups.
+fn split_top_level(tokens: &[TokenTree]) -> Vec<Vec<TokenTree>> {
+    let mut segments = vec![Vec::new()];
+    let mut depth = 0usize;
+    let mut after_dash = false;
+    for token in tokens {
+        let mut is_dash = false;
+        if let TokenTree::Punct(punct) = token {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if !after_dash => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    segments.push(Vec::new());
+                    continue;
+                }
+                '-' => is_dash = punct.spacing() == Spacing::Joint,
+                _ => {}
+            }
+        }
+        after_dash = is_dash;
+        segments.last_mut().unwrap().push(token.clone());
+    }
+    segments.retain(|segment| !segment.is_empty());
+    segments
+}
+
+fn matching_angle(tokens: &[TokenTree], open: usize) -> Result<usize, String> {
+    let mut depth = 0usize;
+    for (pos, token) in tokens.iter().enumerate().skip(open) {
+        if let TokenTree::Punct(punct) = token {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if !is_punct(tokens.get(pos.wrapping_sub(1)), '-') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(pos);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Err("unclosed generic parameter list".to_string())
+}
+
+fn parse_fields(group: Option<&TokenTree>) -> Fields {
+    match group {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            let tokens = group.stream().into_iter().collect::<Vec<TokenTree>>();
+            let names = split_top_level(&tokens)
+                .iter()
+                .filter_map(|field| ident_at(field, skip_attributes_and_visibility(field, 0)))
+                .collect();
+            Fields::Named(names)
+        }
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let tokens = group.stream().into_iter().collect::<Vec<TokenTree>>();
+            Fields::Unnamed(split_top_level(&tokens).len())
+        }
+        _ => Fields::Unit,
+    }
+}
+
+fn parse_variants(group: &TokenTree) -> Result<Vec<Variant>, String> {
+    let tokens = match group {
+        TokenTree::Group(group) => group.stream().into_iter().collect::<Vec<TokenTree>>(),
+        _ => return Err("expected the variants of the enum".to_string()),
+    };
+    split_top_level(&tokens)
+        .iter()
+        .map(|variant| {
+            let pos = skip_attributes_and_visibility(variant, 0);
+            let name = ident_at(variant, pos).ok_or("expected a variant name")?;
+            Ok(Variant {
+                name,
+                fields: parse_fields(variant.get(pos + 1)),
+            })
+        })
+        .collect()
+}
+
+fn parse_item(input: TokenStream) -> Result<Item, String> {
+    let tokens = input.into_iter().collect::<Vec<TokenTree>>();
+    let mut pos = skip_attributes_and_visibility(&tokens, 0);
+    let keyword = ident_at(&tokens, pos).ok_or("expected `struct` or `enum`")?;
+    let name = ident_at(&tokens, pos + 1).ok_or("expected the name of the type")?;
+    pos += 2;
+
+    let mut params = Vec::new();
+    if is_punct(tokens.get(pos), '<') {
+        let close = matching_angle(&tokens, pos)?;
+        params = split_top_level(&tokens[pos + 1..close]);
+        pos = close + 1;
+    }
+
+    let mut body = None;
+    let mut where_clause = Vec::new();
+    let mut in_where = false;
+    for token in &tokens[pos..] {
+        match token {
+            TokenTree::Ident(ident) if !in_where && ident.to_string() == "where" => {
+                in_where = true;
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                body = Some(token.clone());
+                break;
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis && !in_where => {
+                body = Some(token.clone());
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ';' => break,
+            _ if in_where => where_clause.push(token.clone()),
+            _ => {}
+        }
+    }
+
+    let body = match keyword.as_str() {
+        "struct" => Body::Struct(parse_fields(body.as_ref())),
+        "enum" => Body::Enum(parse_variants(
+            body.as_ref().ok_or("expected the variants of the enum")?,
+        )?),
+        _ => {
+            return Err(format!(
+                "PoxHashable cannot be derived for `{}` items",
+                keyword
+            ))
+        }
+    };
+    Ok(Item {
+        name,
+        params,
+        where_clause,
+        body,
+    })
+}
+
+/// Drops a `= default` from a generic parameter, which is not allowed on an impl.
+fn strip_default(param: &[TokenTree]) -> &[TokenTree] {
+    let mut depth = 0usize;
+    for (pos, token) in param.iter().enumerate() {
+        if let TokenTree::Punct(punct) = token {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' => depth = depth.saturating_sub(1),
+                '=' if depth == 0 => return &param[..pos],
+                _ => {}
+            }
+        }
+    }
+    param
+}
+
+fn expand_generics(item: &Item) -> (String, String, String) {
+    let mut impl_params = Vec::new();
+    let mut type_args = Vec::new();
+    let mut predicates = split_top_level(&item.where_clause)
+        .iter()
+        .map(|predicate| to_source(predicate))
+        .collect::<Vec<String>>();
+    for param in &item.params {
+        let param = strip_default(param);
+        impl_params.push(to_source(param));
+        if is_punct(param.first(), '\'') {
+            type_args.push(to_source(&param[..2]));
+        } else if ident_at(param, 0).is_some_and(|ident| ident == "const") {
+            type_args.push(ident_at(param, 1).unwrap_or_default());
+        } else if let Some(ident) = ident_at(param, 0) {
+            predicates.push(format!("{}: {}", ident, TRAIT_PATH));
+            type_args.push(ident);
+        }
+    }
+    if impl_params.is_empty() {
+        return (String::new(), String::new(), String::new());
+    }
+    let where_clause = if predicates.is_empty() {
+        String::new()
+    } else {
+        format!("where {}", predicates.join(", "))
+    };
+    (
+        format!("<{}>", impl_params.join(", ")),
+        format!("<{}>", type_args.join(", ")),
+        where_clause,
+    )
+}
+
+fn encode(value: &str) -> String {
+    format!("{}::pox_encode({}, out);\n", TRAIT_PATH, value)
+}
+
+/// Encodes the fields, which are reached through the expressions in `access`.
+fn expand_fields(fields: &Fields, access: &[String]) -> String {
+    let mut code = encode(&format!("&{}usize", access.len()));
+    if let Fields::Named(ref names) = *fields {
+        for (name, value) in names.iter().zip(access) {
+            code.push_str(&encode(&format!("{:?}", field_name(name))));
+            code.push_str(&encode(value));
+        }
+    } else {
+        for value in access {
+            code.push_str(&encode(value));
+        }
+    }
+    code
+}
+
+fn expand_variant(item_name: &str, variant: &Variant) -> String {
+    let (pattern, bindings) = match variant.fields {
+        Fields::Named(ref names) => {
+            let bindings = (0..names.len())
+                .map(|i| format!("{}{}", BINDING_PREFIX, i))
+                .collect::<Vec<String>>();
+            let fields = names
+                .iter()
+                .zip(&bindings)
+                .map(|(name, binding)| format!("{}: ref {}", name, binding))
+                .collect::<Vec<String>>();
+            (format!(" {{ {} }}", fields.join(", ")), bindings)
+        }
+        Fields::Unnamed(count) => {
+            let bindings = (0..count)
+                .map(|i| format!("{}{}", BINDING_PREFIX, i))
+                .collect::<Vec<String>>();
+            let fields = bindings
+                .iter()
+                .map(|binding| format!("ref {}", binding))
+                .collect::<Vec<String>>();
+            (format!("({})", fields.join(", ")), bindings)
+        }
+        Fields::Unit => (String::new(), Vec::new()),
+    };
+    format!(
+        "{}::{}{} => {{\n{}{}}}\n",
+        item_name,
+        variant.name,
+        pattern,
+        encode(&format!("{:?}", field_name(&variant.name))),
+        expand_fields(&variant.fields, &bindings)
+    )
+}
+
+fn expand(item: &Item) -> String {
+    let body = match item.body {
+        Body::Struct(ref fields) => {
+            let access = match *fields {
+                Fields::Named(ref names) => {
+                    names.iter().map(|name| format!("&self.{}", name)).collect()
+                }
+                Fields::Unnamed(count) => (0..count).map(|i| format!("&self.{}", i)).collect(),
+                Fields::Unit => Vec::new(),
+            };
+            expand_fields(fields, &access)
+        }
+        Body::Enum(ref variants) => format!(
+            "match *self {{\n{}}}\n",
+            variants
+                .iter()
+                .map(|variant| expand_variant(&item.name, variant))
+                .collect::<String>()
+        ),
+    };
+    let (impl_params, type_args, where_clause) = expand_generics(item);
+    format!(
+        "impl{} {} for {}{} {} {{\nfn pox_encode(&self, out: &mut ::std::vec::Vec<u8>) {{\n{}}}\n}}\n",
+        impl_params, TRAIT_PATH, item.name, type_args, where_clause, body
+    )
+}
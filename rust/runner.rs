@@ -27,7 +27,15 @@
 
 
 extern crate libpoxh;
-use libpoxh::{pox_hash, PoxDigest};
+use libpoxh::{pox_hash, PoxDigest, PoxHashable, PoxHasher, RadixEncoding};
+
+// Included rather than declared as a file module, so that tests/runner.rs can include
+// this file from another directory
+#[allow(dead_code)]
+mod splitmix {
+    include!("splitmix.rs");
+}
+use self::splitmix::SplitMix64;
 
 const MAX_FLAG_SIZE: usize = 24;
 const MIN_FLAG_SIZE: usize = 3;
@@ -59,21 +67,77 @@ const FLAG_HELP: char = '?';
 const FLAG_DASH: char = '-';
 const FLAG_NHEADER: char = 'z';
 const FLAG_ECHO: char = 'e';
+const FLAG_JSON: char = 'j';
+const FLAG_JSONL: char = 'l';
+const FLAG_CSV: char = 'c';
 
 const FILE_PREFIX: &'static str = "file=";
 const FILE_PREFIX_LEN: usize = 5;
 
+const GEN_PREFIX: &'static str = "gen=";
+const GEN_PREFIX_LEN: usize = 4;
+const GEN_SEP: char = ':';
+const GEN_ZEROS: &'static str = "zeros";
+const GEN_RAND: &'static str = "rand";
+const GEN_REPEAT: &'static str = "repeat";
+const GEN_COUNTER: &'static str = "counter";
+const GEN_USAGE: &'static str = "zeros:N, rand:SEED:N, repeat:PATTERN:N or counter:N";
+const SIZE_SUFFIXES: &'static [(char, u64)] = &[('K', 1 << 10), ('M', 1 << 20), ('G', 1 << 30)];
+
+const STDIN_DASH: &'static str = "-";
+const STDIN_NAME: &'static str = "stdin";
+
+const SOURCE_ARG: &'static str = "arg";
+const SOURCE_INT: &'static str = "int";
+const SOURCE_FILE: &'static str = "file";
+const SOURCE_STDIN: &'static str = "stdin";
+const SOURCE_GEN: &'static str = "gen";
+const SOURCE_HEX: &'static str = "hex";
+const SOURCE_B64: &'static str = "b64";
+const SOURCE_JOINED: &'static str = "joined";
+const SOURCE_LINE: &'static str = "line";
+
+const FIELD_NAMES: &'static [&'static str] = &[
+    "bytes",
+    "words",
+    "doubles",
+    "quad",
+    "sexdigest",
+    "vigdigest",
+    "hexdigest",
+    "tetdigest",
+    "duodigest",
+    "octdigest",
+    "sendigest",
+    "bindigest",
+];
+const FIELD_TIME: &'static str = "time_ns";
+const CSV_LIST_SEP: &'static str = " ";
+
 const INT_PREFIX: &'static str = "int=";
 const INT_PREFIX_LEN: usize = 4;
 
-const MAX_BIN: usize = 8;
-const MAX_OCT: usize = 5;
-const MAX_HEX: usize = 2;
 
 const HEX_PREFIX: &'static str = "0x";
 const BIN_PREFIX: &'static str = "0b";
 const OCT_PREFIX: &'static str = "0o";
 const BASE_PREFIX_NUM: usize = 2;
+const INT_LIST_SEP: char = ',';
+
+const HEX_INPUT_PREFIX: &'static str = "hex=";
+const HEX_INPUT_PREFIX_LEN: usize = 4;
+const HEX_INPUT_SEP: char = ':';
+const B64_INPUT_PREFIX: &'static str = "b64=";
+const B64_INPUT_PREFIX_LEN: usize = 4;
+const B64_PAD: char = '=';
+const TYPED_PREFIXES: &'static [(&'static str, usize, bool)] = &[
+    ("u16le=", 2, false),
+    ("u16be=", 2, true),
+    ("u32le=", 4, false),
+    ("u32be=", 4, true),
+    ("u64le=", 8, false),
+    ("u64be=", 8, true),
+];
 
 const NS_TO_NS: u128 = 100;
 const NS_TO_US: u128 = 1000;
@@ -83,36 +147,271 @@ const NS_TO_MM: u128 = 60000000000;
 
 const E_NOT_TRUNC_LEN: usize = 4;
 
-const WRONG_FLAGS: &'static [(char, char)] = &[
-    ('G', 'g'),
-    ('V', 'v'),
-    ('O', 'o'),
-    ('T', 't'),
-    ('S', 's'),
-    ('H', 'h'),
-    ('n', 'N'),
-    ('W', '4'),
-    ('w', '4'),
-    ('q', '1'),
-    ('Q', '1'),
-    ('/', '?'),
-    ('=', '+'),
-    ('B', 'b'),
-    ('E', '*'),
-    ('A', '*'),
-    ('>', '?'),
-    ('&', '*'),
-    ('r', 't'),
-    ('y', 't'),
-    ('f', 'g'),
-    ('x', 'z'),
-    ('Z', 'z'),
-    ('a', 'z'),
-    ('E', 'e'),
-    ('w', 'e'),
-    ('r', 'e'),
-    ('i', 'e'),
+const RUNNER_VERSION: &'static str = "1.0.0";
+const SPEC_VERSION: &'static str = "PoxHash v2";
+
+const LONG_PREFIX: &'static str = "--";
+const LONG_VALUE_SEP: char = '=';
+const LONG_LIST_SEP: char = ',';
+const LONG_ONLY: char = '\0';
+const OPTION_VERSION: &'static str = "version";
+const OPTION_COMPLETIONS: &'static str = "completions";
+const OPTION_SUM: &'static str = "sum";
+const OPTION_TAG: &'static str = "tag";
+const OPTION_CHECK: &'static str = "check";
+const OPTION_QUIET: &'static str = "quiet";
+const OPTION_IGNORE_MISSING: &'static str = "ignore-missing";
+const OPTION_RECURSIVE: &'static str = "recursive";
+const OPTION_INCLUDE: &'static str = "include";
+const OPTION_EXCLUDE: &'static str = "exclude";
+const OPTION_FOLLOW_SYMLINKS: &'static str = "follow-symlinks";
+const OPTION_HIDDEN: &'static str = "hidden";
+const OPTION_EXPECT: &'static str = "expect";
+const OPTION_LINES: &'static str = "lines";
+const OPTION_KEEP_NEWLINES: &'static str = "keep-newlines";
+const OPTION_CRLF: &'static str = "crlf";
+const OPTION_SKIP_EMPTY: &'static str = "skip-empty";
+const OPTION_FILES_FROM: &'static str = "files-from";
+const OPTION_NULL: &'static str = "null";
+
+const SUBCOMMAND_BENCH: &'static str = "bench";
+const BENCH_ITERATIONS: &'static str = "iterations";
+const BENCH_TIME: &'static str = "time";
+const BENCH_WARMUP: &'static str = "warmup";
+const BENCH_SAVE: &'static str = "save";
+const BENCH_COMPARE: &'static str = "compare";
+const BENCH_SWEEP: &'static str = "sweep";
+const BENCH_SCALE: &'static str = "scale";
+const BENCH_STEPS: &'static str = "steps";
+const BENCH_CSV: &'static str = "csv";
+const BENCH_ASCII: &'static str = "ascii";
+const SCALE_LOG: &'static str = "log";
+const SCALE_LINEAR: &'static str = "linear";
+const BENCH_DEFAULT_TIME: f64 = 1.0;
+const BENCH_DEFAULT_WARMUP: usize = 10;
+const BENCH_MIN_SAMPLES: usize = 2;
+const BENCH_MAX_SAMPLES: usize = 1_000_000;
+const BENCH_P99: f64 = 0.99;
+const BENCH_Z_95: f64 = 1.959964;
+const BENCH_BASELINE_HEADER: &'static str = "POXH-BENCH 1";
+const BENCH_DURATION_UNITS: &'static [(&'static str, f64)] = &[("s", 1e9), ("ms", 1e6), ("us", 1e3), ("ns", 1.0)];
+const BYTES_IN_MIB: f64 = 1048576.0;
+const SWEEP_DEFAULT_STEPS: usize = 16;
+const SWEEP_BLOCK_BYTES: usize = 64; // every byte is padded into one word of a 64-word block
+const SWEEP_SEED: u64 = 0x5057_4545_5053_4545;
+const SWEEP_CSV_HEADER: &'static str =
+    "bytes,blocks,iterations,min_ns,median_ns,mean_ns,stddev_ns,p99_ns,ns_per_byte,mib_per_s";
+const CHART_WIDTH: usize = 40;
+const CHART_UNICODE: &'static [char] = &['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+const CHART_ASCII: char = '#';
+
+const EXIT_FAILED: i32 = 1;
+const EXIT_ERROR: i32 = 2;
+
+const CHECKSUM_TAG: &'static str = "POXH";
+const CHECKSUM_TAG_OPEN: &'static str = " (";
+const CHECKSUM_TAG_CLOSE: &'static str = ") = ";
+const CHECKSUM_SEP: &'static str = "  ";
+const CHECKSUM_BINARY_MARK: char = '*';
+const CHECKSUM_STATUS_OK: &'static str = "OK";
+const CHECKSUM_STATUS_FAILED: &'static str = "FAILED";
+const CHECKSUM_STATUS_MISSING: &'static str = "MISSING";
+
+const TREE_TAG: &'static str = "POXH-TREE";
+const TREE_PATH_SEP: char = '/';
+const TREE_HIDDEN_PREFIX: char = '.';
+const TREE_KIND_FILE: u8 = b'f';
+const TREE_KIND_SYMLINK: u8 = b'l';
+const TREE_KIND_DIR: u8 = b'd';
+const GLOB_ANY: char = '*';
+const GLOB_ONE: char = '?';
+
+const BENCHMARK_ONLY: &'static str = "only";
+const BENCHMARK_UNITS: &'static [(&'static str, char)] = &[
+    ("ns", FLAG_NS),
+    ("us", FLAG_US),
+    ("ms", FLAG_MS),
+    ("s", FLAG_SS),
+    ("m", FLAG_MM),
+];
+const BENCHMARK_VALUES: &'static [&'static str] = &["ns", "us", "ms", "s", "m", "only"];
+
+const SHELL_BASH: &'static str = "bash";
+const SHELL_ZSH: &'static str = "zsh";
+const SHELL_FISH: &'static str = "fish";
+const SHELLS: &'static [&'static str] = &[SHELL_BASH, SHELL_ZSH, SHELL_FISH];
+
+const DIGEST_FLAGS: &'static [char] = &[
+    FLAG_EVERTHING,
+    FLAG_ALL_NON_DEC,
+    FLAG_ALL_DECIMAL,
+    FLAG_BYTES,
+    FLAG_WORDS,
+    FLAG_DOUBLES,
+    FLAG_QUAD,
+    FLAG_SEX,
+    FLAG_VIG,
+    FLAG_HEX,
+    FLAG_TET,
+    FLAG_DUO,
+    FLAG_OCT,
+    FLAG_SEN,
+    FLAG_BIN,
 ];
+const DEFAULT_DIGEST_FLAG: char = FLAG_HEX;
+
+struct LongOption {
+    name: &'static str,
+    flag: char,
+    value_name: &'static str,
+    values: &'static [&'static str],
+    value_required: bool,
+    help: &'static str,
+}
+
+impl LongOption {
+    const fn flag(name: &'static str, flag: char, help: &'static str) -> LongOption {
+        LongOption {
+            name,
+            flag,
+            value_name: "",
+            values: &[],
+            value_required: false,
+            help,
+        }
+    }
+
+    const fn valued(
+        name: &'static str,
+        flag: char,
+        value_name: &'static str,
+        values: &'static [&'static str],
+        value_required: bool,
+        help: &'static str,
+    ) -> LongOption {
+        LongOption {
+            name,
+            flag,
+            value_name,
+            values,
+            value_required,
+            help,
+        }
+    }
+
+    fn usage(&self) -> String {
+        if self.value_name.is_empty() {
+            format!("{}{}", LONG_PREFIX, self.name)
+        } else if self.value_required {
+            format!("{}{}{}{}", LONG_PREFIX, self.name, LONG_VALUE_SEP, self.value_name)
+        } else {
+            format!("{}{}[{}{}]", LONG_PREFIX, self.name, LONG_VALUE_SEP, self.value_name)
+        }
+    }
+}
+
+// Help, suggestions and completion scripts are all generated from this table
+const LONG_OPTIONS: &'static [LongOption] = &[
+    LongOption::flag("echo", FLAG_ECHO, "Echo argument"),
+    LongOption::flag("no-header", FLAG_NHEADER, "Don't print header message"),
+    LongOption::valued(
+        "benchmark",
+        FLAG_BENCHMARK,
+        "UNIT",
+        BENCHMARK_VALUES,
+        false,
+        "Benchmark run, UNIT is a comma-separated list of ns, us, ms, s and m, or only",
+    ),
+    LongOption::flag("join", FLAG_JOIN, "Join arguments with space (byte 32)"),
+    LongOption::flag("all", FLAG_EVERTHING, "Print every digest"),
+    LongOption::flag("non-decimal", FLAG_ALL_NON_DEC, "Print every non-decimal digest"),
+    LongOption::flag("decimal", FLAG_ALL_DECIMAL, "Print every decimal digest"),
+    LongOption::flag("bytes", FLAG_BYTES, "Print bytes digest (eight unsigned 8-bit integers)"),
+    LongOption::flag("words", FLAG_WORDS, "Print words digest (four unsigned 16-bit integers)"),
+    LongOption::flag("doubles", FLAG_DOUBLES, "Print doubles digest (two unsigned 32-bit integers)"),
+    LongOption::flag("quad", FLAG_QUAD, "Print quad digest (one unsigned 64-bit integer)"),
+    LongOption::flag("sex", FLAG_SEX, "Print sexagesimal digest (base sixty)"),
+    LongOption::flag("vig", FLAG_VIG, "Print vigesimal digest (base twenty)"),
+    LongOption::flag("hex", FLAG_HEX, "Print hexadecimal digest (base sixteen)"),
+    LongOption::flag("tet", FLAG_TET, "Print tetradecimal digest (base fourteen)"),
+    LongOption::flag("duo", FLAG_DUO, "Print duodecimal digest (base twelve)"),
+    LongOption::flag("oct", FLAG_OCT, "Print octal digest (base eight)"),
+    LongOption::flag("sen", FLAG_SEN, "Print senary digest (base six)"),
+    LongOption::flag("bin", FLAG_BIN, "Print binary digest (base two)"),
+    LongOption::flag("json", FLAG_JSON, "Print a JSON array with one record per message"),
+    LongOption::flag("jsonl", FLAG_JSONL, "Print one JSON record per line (JSON Lines)"),
+    LongOption::flag("csv", FLAG_CSV, "Print CSV with a header row and one row per message"),
+    LongOption::flag("help", FLAG_HELP, "Print Help"),
+    LongOption::flag(OPTION_VERSION, LONG_ONLY, "Print the runner version"),
+    LongOption::valued(
+        OPTION_COMPLETIONS,
+        LONG_ONLY,
+        "SHELL",
+        SHELLS,
+        true,
+        "Print a completion script for bash, zsh or fish",
+    ),
+    LongOption::flag(OPTION_SUM, LONG_ONLY, "Print a checksum line for each file in the chosen digest"),
+    LongOption::flag(OPTION_TAG, LONG_ONLY, "Print BSD-style `POXH (path) = digest` checksum lines"),
+    LongOption::valued(
+        OPTION_CHECK,
+        LONG_ONLY,
+        "FILE",
+        &[],
+        true,
+        "Verify the files listed in a checksum file, `-` for standard input",
+    ),
+    LongOption::flag(OPTION_QUIET, LONG_ONLY, "Don't print OK for each verified file"),
+    LongOption::flag(OPTION_IGNORE_MISSING, LONG_ONLY, "Don't fail or report on missing files"),
+    LongOption::flag(OPTION_RECURSIVE, LONG_ONLY, "Hash every file under the given directories and print a root digest"),
+    LongOption::valued(OPTION_INCLUDE, LONG_ONLY, "GLOB", &[], true, "Only hash files matching this pattern, may be repeated"),
+    LongOption::valued(OPTION_EXCLUDE, LONG_ONLY, "GLOB", &[], true, "Skip files and directories matching this pattern, may be repeated"),
+    LongOption::flag(OPTION_FOLLOW_SYMLINKS, LONG_ONLY, "Hash what symbolic links point to instead of their targets' paths"),
+    LongOption::flag(OPTION_HIDDEN, LONG_ONLY, "Don't skip files and directories whose names start with a dot"),
+    LongOption::flag(OPTION_LINES, LONG_ONLY, "Hash every line of the given files, or of standard input, on its own"),
+    LongOption::flag(OPTION_KEEP_NEWLINES, LONG_ONLY, "Hash each line with its line ending"),
+    LongOption::flag(OPTION_CRLF, LONG_ONLY, "Strip `\\r\\n` line endings as well as `\\n`"),
+    LongOption::flag(OPTION_SKIP_EMPTY, LONG_ONLY, "Don't hash empty lines"),
+    LongOption::valued(
+        OPTION_FILES_FROM,
+        LONG_ONLY,
+        "FILE",
+        &[],
+        true,
+        "Hash every file listed in FILE, one path per line, `-` for standard input",
+    ),
+    LongOption::flag(OPTION_NULL, LONG_ONLY, "Paths in `--files-from` are separated by NUL, as `find -print0` writes"),
+    LongOption::valued(
+        OPTION_EXPECT,
+        LONG_ONLY,
+        "DIGEST",
+        &[],
+        true,
+        "Exit with status 1 unless the digest matches, given in any non-decimal base",
+    ),
+];
+
+const BENCH_OPTIONS: &'static [LongOption] = &[
+    LongOption::valued(BENCH_ITERATIONS, LONG_ONLY, "N", &[], true, "Time exactly N hashes of each input"),
+    LongOption::valued(BENCH_TIME, LONG_ONLY, "SECONDS", &[], true, "Time each input for this long, 1 by default"),
+    LongOption::valued(BENCH_WARMUP, LONG_ONLY, "N", &[], true, "Hash each input N times before timing, 10 by default"),
+    LongOption::valued(BENCH_SAVE, LONG_ONLY, "FILE", &[], true, "Write the results to a baseline file"),
+    LongOption::valued(BENCH_COMPARE, LONG_ONLY, "FILE", &[], true, "Compare the results against a baseline file"),
+    LongOption::valued(BENCH_SWEEP, LONG_ONLY, "MAX", &[], true, "Time generated messages of 1 to MAX bytes instead of inputs"),
+    LongOption::valued(
+        BENCH_SCALE,
+        LONG_ONLY,
+        "SCALE",
+        &[SCALE_LOG, SCALE_LINEAR],
+        true,
+        "Space the sweep sizes on a log or linear scale, log by default",
+    ),
+    LongOption::valued(BENCH_STEPS, LONG_ONLY, "N", &[], true, "Number of sweep sizes, 16 by default"),
+    LongOption::valued(BENCH_CSV, LONG_ONLY, "FILE", &[], true, "Write the raw sweep data to a CSV file"),
+    LongOption::flag(BENCH_ASCII, LONG_ONLY, "Draw the sweep chart with `#` instead of block characters"),
+    LongOption::flag("help", FLAG_HELP, "Print help for `bench`"),
+];
+
 
 
 fn to_e_notation(num_in: f64, places: usize) -> String {
@@ -172,7 +471,7 @@ macro_rules! error_out {
         eprint!($message);
         eprintln!();
         eprint!("\x1b[1;31mError occurred\x1b[0m. Please pass \x1b[1;34m-?-\x1b[0m to show help\n");
-        std::process::exit(1);
+        std::process::exit(EXIT_ERROR);
     }};
 }
 
@@ -184,12 +483,15 @@ fn print_help(exec: String) {
     print!("{} -*+^- mywod to be joined\n", exec);
     print!("{} -Dhob- word1 word 2\n", exec);
     print!("{} -^^+- large seq  to join and  benchmark\n", exec);
-    print!(
-        "wget -qO- www.example.com | xargs bash -c '{} -h+- $@'\n",
-        exec
-    );
-    print!("If an argument stats with `{}`, it will lead to file read attempt, unless `{}` is passed\n", FILE_PREFIX, FLAG_JOIN);
+    print!("{} --hex --bytes --benchmark=us,ms word1 word2\n", exec);
+    print!("wget -qO- www.example.com | {} -h- {}\n", exec, STDIN_DASH);
+    print!("If an argument stats with `{}`, the bytes of that file will be hashed, unless `{}` is passed\n", FILE_PREFIX, FLAG_JOIN);
+    print!("If an argument is `{}` or `{}`, standard input will be hashed, unless `{}` is passed\n", STDIN_DASH, STDIN_NAME, FLAG_JOIN);
     print!("If an argument stats with `{}`, it will parse the int, prefixes `0b`, `0o` and `0x` for bin, oct and hex and none for decimal apply\n", INT_PREFIX);
+    print!("If an argument starts with `{}`, hex digits are decoded, whitespace and `{}` are skipped\n", HEX_INPUT_PREFIX, HEX_INPUT_SEP);
+    print!("If an argument starts with `{}`, base64 (standard or URL-safe, padding optional) is decoded\n", B64_INPUT_PREFIX);
+    print!("If an argument starts with `u16le=`, `u32be=` and so on, the comma-separated ints are written at that width and byte order\n");
+    print!("If an argument starts with `{}`, generated bytes will be hashed: {}, sizes take K, M or G\n", GEN_PREFIX, GEN_USAGE);
     println!();
     print!("\x1b[1;32mFlags:\x1b[0m\n");
     print!("\x1b[1;33m\t`{}`\x1b[0m: Echo argument\n", FLAG_ECHO);
@@ -270,24 +572,904 @@ fn print_help(exec: String) {
     print!("\x1b[1;33m\t`{}`\x1b[0m: Print total timestamp delta in milliseconds\n", FLAG_MS);
     print!("\x1b[1;33m\t`{}`\x1b[0m: Print total timestamp delta in seconds\n", FLAG_SS);
     print!("\x1b[1;33m\t`{}`\x1b[0m: Print total timestamp delta in minutes\n", FLAG_MM);
+    print!("\x1b[1;33m\t`{}`\x1b[0m: Print a JSON array with one record per message\n", FLAG_JSON);
+    print!("\x1b[1;33m\t`{}`\x1b[0m: Print one JSON record per line (JSON Lines)\n", FLAG_JSONL);
+    print!("\x1b[1;33m\t`{}`\x1b[0m: Print CSV with a header row and one row per message\n", FLAG_CSV);
     print!("\x1b[1;33m\t`{}`\x1b[0m: Print Help\n\n", FLAG_HELP);
-    std::process::exit(1);
+    print!("\x1b[1;32mLong options\x1b[0m (instead of the flag argument, anywhere among the inputs, `{}` ends them):\n", LONG_PREFIX);
+    for option in LONG_OPTIONS {
+        if option.flag == LONG_ONLY {
+            print!("\x1b[1;33m\t{}\x1b[0m: {}\n", option.usage(), option.help);
+        } else {
+            print!("\x1b[1;33m\t{}\x1b[0m (`{}`): {}\n", option.usage(), option.flag, option.help);
+        }
+    }
+    print!("Without a digest option, the hexadecimal digest is printed\n\n");
+    std::process::exit(0);
+}
+
+fn print_version() {
+    print!("PoxHashRunner {} | Rust | {}\n", RUNNER_VERSION, SPEC_VERSION);
+    std::process::exit(0);
+}
+
+fn is_known_flag(flag: char) -> bool {
+    [FLAG_DASH, FLAG_NS, FLAG_US, FLAG_MS, FLAG_SS, FLAG_MM].contains(&flag)
+        || LONG_OPTIONS.iter().any(|option| option.flag == flag)
+}
+
+fn suggest_flag(flag: char) -> Option<&'static LongOption> {
+    let mut flagged = LONG_OPTIONS.iter().filter(|option| option.flag != LONG_ONLY);
+    let same_letter = flagged.clone().find(|option| option.flag.eq_ignore_ascii_case(&flag));
+    same_letter.or_else(|| flagged.find(|option| option.name.starts_with(flag.to_ascii_lowercase())))
 }
 
 fn check_for_wrong_flags(flags: &String) {
-    for flag in flags.chars() {
-        for (wrong_flag, right_flag) in WRONG_FLAGS {
-            if flag == *wrong_flag {
-                print!(
-                    "No flag for `{}`, perhaps you meant `{}`?",
-                    flag, right_flag
-                );
-                error_out!("Flag errror");
+    for flag in flags.chars().filter(|flag| !is_known_flag(*flag)) {
+        if let Some(option) = suggest_flag(flag) {
+            print!(
+                "No flag for `{}`, perhaps you meant `{}` ({}{})?",
+                flag, option.flag, LONG_PREFIX, option.name
+            );
+            error_out!("Flag errror");
+        }
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut row = (0..=b.len()).collect::<Vec<usize>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + if ca == *cb { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = std::cmp::min(substitution, std::cmp::min(row[j], row[j + 1]) + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn find_long_option(options: &'static [LongOption], name: &str) -> &'static LongOption {
+    if let Some(option) = options.iter().find(|option| option.name == name) {
+        return option;
+    }
+    let nearest = options
+        .iter()
+        .min_by_key(|option| edit_distance(option.name, name))
+        .unwrap();
+    if edit_distance(nearest.name, name) <= std::cmp::max(2, name.len() / 3) {
+        print!("No option `{}{}`, perhaps you meant `{}{}`?", LONG_PREFIX, name, LONG_PREFIX, nearest.name);
+    } else {
+        print!("No option `{}{}`", LONG_PREFIX, name);
+    }
+    error_out!("Option error");
+}
+
+fn check_option_value(option: &LongOption, value: Option<&str>) -> Vec<String> {
+    match value {
+        None if option.value_required => {
+            if option.values.is_empty() {
+                print!("`{}` needs a value", option.usage());
+            } else {
+                print!("`{}` needs a value: {}", option.usage(), option.values.join(", "));
             }
+            error_out!("Option error");
         }
+        None => Vec::new(),
+        Some(_) if option.value_name.is_empty() => {
+            print!("`{}{}` does not take a value", LONG_PREFIX, option.name);
+            error_out!("Option error");
+        }
+        Some(value) if option.values.is_empty() => vec![value.to_string()],
+        Some(value) => value
+            .split(LONG_LIST_SEP)
+            .map(|part| match option.values.iter().find(|known| **known == part) {
+                Some(known) => known.to_string(),
+                None => {
+                    print!("`{}` is not a value of `{}`, use one of: {}", part, option.usage(), option.values.join(", "));
+                    error_out!("Option error");
+                }
+            })
+            .collect(),
     }
 }
 
+fn to_completion_name(exec: &String) -> String {
+    exec.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+}
+
+fn to_bash_compgen(option: &LongOption) -> String {
+    if option.values.is_empty() {
+        "COMPREPLY=($(compgen -f -- \"$cur\"))".to_string()
+    } else {
+        format!("COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))", option.values.join(" "))
+    }
+}
+
+fn print_bash_completions(exec: &String) {
+    let function = format!("_{}_completions", to_completion_name(exec));
+    let mut words = Vec::new();
+    for option in LONG_OPTIONS {
+        words.push(format!("{}{}", LONG_PREFIX, option.name));
+        if !option.value_name.is_empty() {
+            words.push(format!("{}{}{}", LONG_PREFIX, option.name, LONG_VALUE_SEP));
+        }
+    }
+    print!("{}() {{\n", function);
+    print!("    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\" prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\" option=\"\"\n");
+    print!("    if [[ \"$cur\" == \"{}\" ]]; then\n", LONG_VALUE_SEP);
+    print!("        option=\"$prev\"\n        cur=\"\"\n");
+    print!("    elif [[ \"$prev\" == \"{}\" ]]; then\n", LONG_VALUE_SEP);
+    print!("        option=\"${{COMP_WORDS[COMP_CWORD-2]}}\"\n");
+    print!("    fi\n");
+    print!("    case \"$option\" in\n");
+    for option in LONG_OPTIONS.iter().filter(|option| !option.value_name.is_empty()) {
+        print!("        {}{}) {}; return ;;\n", LONG_PREFIX, option.name, to_bash_compgen(option));
+    }
+    print!("    esac\n");
+    print!("    case \"$prev\" in\n");
+    for option in LONG_OPTIONS.iter().filter(|option| option.value_required) {
+        print!("        {}{}) {}; return ;;\n", LONG_PREFIX, option.name, to_bash_compgen(option));
+    }
+    print!("    esac\n");
+    print!("    if [[ \"$cur\" == {}* ]]; then\n", LONG_PREFIX);
+    print!("        COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))\n", words.join(" "));
+    print!("        [[ ${{#COMPREPLY[@]}} -eq 1 && \"${{COMPREPLY[0]}}\" == *{} ]] && compopt -o nospace\n", LONG_VALUE_SEP);
+    print!("    else\n");
+    print!("        COMPREPLY=($(compgen -f -- \"$cur\"))\n");
+    print!("    fi\n");
+    print!("}}\n");
+    print!("complete -o default -F {} {}\n", function, exec);
+}
+
+fn to_zsh_description(help: &str) -> String {
+    help.replace('\'', "'\\''").replace('[', "\\[").replace(']', "\\]").replace(':', "\\:")
+}
+
+fn print_zsh_completions(exec: &String) {
+    print!("#compdef {}\n\n", exec);
+    print!("_arguments \\\n");
+    for option in LONG_OPTIONS {
+        let help = to_zsh_description(option.help);
+        let action = if option.values.is_empty() {
+            "_files".to_string()
+        } else {
+            format!("({})", option.values.join(" "))
+        };
+        if option.value_name.is_empty() {
+            print!("  '{}{}[{}]' \\\n", LONG_PREFIX, option.name, help);
+        } else if option.value_required {
+            print!(
+                "  '{}{}{}[{}]:{}:{}' \\\n",
+                LONG_PREFIX,
+                option.name,
+                LONG_VALUE_SEP,
+                help,
+                option.value_name.to_lowercase(),
+                action
+            );
+        } else {
+            print!(
+                "  '{}{}{}-[{}]::{}:{}' \\\n",
+                LONG_PREFIX,
+                option.name,
+                LONG_VALUE_SEP,
+                help,
+                option.value_name.to_lowercase(),
+                action
+            );
+        }
+    }
+    print!("  '*:input:_files'\n");
+}
+
+fn print_fish_completions(exec: &String) {
+    for option in LONG_OPTIONS {
+        let help = option.help.replace('\\', "\\\\").replace('\'', "\\'");
+        if option.value_name.is_empty() {
+            print!("complete -c {} -l {} -d '{}'\n", exec, option.name, help);
+        } else if option.values.is_empty() {
+            print!("complete -c {} -l {} -r -F -d '{}'\n", exec, option.name, help);
+        } else {
+            let required = if option.value_required { " -r" } else { "" };
+            print!(
+                "complete -c {} -l {}{} -f -a '{}' -d '{}'\n",
+                exec,
+                option.name,
+                required,
+                option.values.join(" "),
+                help
+            );
+        }
+    }
+}
+
+fn print_completions(shell: &str, exec: &String) {
+    match shell {
+        SHELL_BASH => print_bash_completions(exec),
+        SHELL_ZSH => print_zsh_completions(exec),
+        _ => print_fish_completions(exec),
+    }
+    std::process::exit(0);
+}
+
+fn assert_legacy_flags(argv: &Vec<String>) -> bool {
+    match argv.get(1) {
+        Some(arg) => arg.starts_with(FLAG_DASH) && !arg.starts_with(LONG_PREFIX) && arg != STDIN_DASH,
+        None => false,
+    }
+}
+
+#[derive(Default)]
+struct ChecksumOptions {
+    generate: bool,
+    check: Option<String>,
+    tag: bool,
+    quiet: bool,
+    ignore_missing: bool,
+    recursive: bool,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    follow_symlinks: bool,
+    hidden: bool,
+}
+
+impl ChecksumOptions {
+    fn is_requested(&self) -> bool {
+        self.generate || self.check.is_some() || self.recursive || self.tag || self.quiet || self.ignore_missing
+            || self.has_tree_options()
+    }
+
+    fn has_tree_options(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty() || self.follow_symlinks || self.hidden
+    }
+}
+
+struct TreeEntry {
+    path: String,
+    kind: u8,
+    digest: PoxDigest,
+}
+
+fn open_regular_file<P: AsRef<std::path::Path>>(fpath: P) -> Option<std::fs::File> {
+    if fpath.as_ref().is_dir() {
+        return None;
+    }
+    std::fs::File::open(fpath).ok()
+}
+
+// Listed paths are raw bytes, so a name that is not UTF-8 still opens where the OS allows it
+#[cfg(unix)]
+fn path_from_bytes(bytes: &[u8]) -> std::path::PathBuf {
+    use std::os::unix::ffi::OsStrExt;
+    std::path::PathBuf::from(std::ffi::OsStr::from_bytes(bytes))
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: &[u8]) -> std::path::PathBuf {
+    std::path::PathBuf::from(String::from_utf8_lossy(bytes).to_string())
+}
+
+fn get_checksum_field(flag_word: &String) -> &'static str {
+    let inner = &flag_word[1..flag_word.len() - 1];
+    let fields = get_requested_fields(flag_word);
+    let only_digests = inner.chars().all(|flag| flag == FLAG_NHEADER || DIGEST_FLAGS.contains(&flag));
+    if !only_digests || fields.len() != 1 || !fields[0].ends_with("digest") {
+        error_out!("`--sum` and `--check` take exactly one non-decimal digest option and no other flags");
+    }
+    fields[0]
+}
+
+fn parse_checksum_line(line: &[u8]) -> Option<(&str, &[u8])> {
+    let tag_prefix = format!("{}{}", CHECKSUM_TAG, CHECKSUM_TAG_OPEN);
+    let tag_close = CHECKSUM_TAG_CLOSE.as_bytes();
+    let (digest, path) = if line.starts_with(tag_prefix.as_bytes()) {
+        let index = line.windows(tag_close.len()).rposition(|window| window == tag_close)?;
+        (&line[index + tag_close.len()..], &line[tag_prefix.len()..index])
+    } else {
+        let index = line.iter().position(|byte| *byte == b' ')?;
+        let rest = &line[index + 1..];
+        if !rest.starts_with(b" ") && !rest.starts_with(&[CHECKSUM_BINARY_MARK as u8]) {
+            return None;
+        }
+        (&line[..index], &rest[1..])
+    };
+    if digest.is_empty() || path.is_empty() {
+        return None;
+    }
+    Some((std::str::from_utf8(digest).ok()?, path))
+}
+
+fn digests_match(field: &str, actual: &str, expected: &str) -> bool {
+    if field == "hexdigest" {
+        actual.eq_ignore_ascii_case(expected)
+    } else {
+        actual == expected
+    }
+}
+
+fn print_checksums(options: &ChecksumOptions, field: &str, inputs: &Vec<String>) {
+    if inputs.is_empty() {
+        error_out!("You must pass at least one file to `--sum`");
+    } else if inputs.iter().filter(|path| *path == STDIN_DASH).count() > 1 {
+        error_out!("Standard input can only be hashed once");
+    }
+    let mut missing = 0usize;
+    for path in inputs {
+        let digest = if path == STDIN_DASH {
+            hash_reader(&mut std::io::stdin().lock()).0
+        } else {
+            match open_regular_file(path) {
+                Some(mut file) => hash_reader(&mut file).0,
+                None => {
+                    eprint!("{}: {}\n", path, CHECKSUM_STATUS_MISSING);
+                    missing += 1;
+                    continue;
+                }
+            }
+        };
+        let digest = field_to_csv(&digest, field);
+        if options.tag {
+            print!("{}{}{}{}{}\n", CHECKSUM_TAG, CHECKSUM_TAG_OPEN, path, CHECKSUM_TAG_CLOSE, digest);
+        } else {
+            print!("{}{}{}\n", digest, CHECKSUM_SEP, path);
+        }
+    }
+    std::process::exit(if missing > 0 { EXIT_FAILED } else { 0 });
+}
+
+fn verify_checksums(options: &ChecksumOptions, field: &str, list_path: &String) {
+    let mut reader = open_bulk_source(list_path);
+    let (mut matched, mut failed, mut missing, mut malformed) = (0usize, 0usize, 0usize, 0usize);
+    let tree_prefix = format!("{}{}", TREE_TAG, CHECKSUM_TAG_OPEN);
+    let mut line = Vec::new();
+    let mut line_number = 0usize;
+    while read_bulk_entry(&mut reader, b'\n', &mut line) {
+        line_number += 1;
+        for ending in [b'\n', b'\r'].iter() {
+            if line.last() == Some(ending) {
+                line.pop();
+            }
+        }
+        if line.is_empty() || line.starts_with(tree_prefix.as_bytes()) {
+            continue;
+        }
+        let (expected, path_bytes) = match parse_checksum_line(&line) {
+            Some(entry) => entry,
+            None => {
+                eprint!("{}: {}: improperly formatted checksum line\n", list_path, line_number);
+                malformed += 1;
+                continue;
+            }
+        };
+        let path = String::from_utf8_lossy(path_bytes);
+        let mut file = match open_regular_file(path_from_bytes(path_bytes)) {
+            Some(file) => file,
+            None => {
+                if !options.ignore_missing {
+                    print!("{}: {}\n", path, CHECKSUM_STATUS_MISSING);
+                    missing += 1;
+                }
+                continue;
+            }
+        };
+        let actual = field_to_csv(&hash_reader(&mut file).0, field);
+        if digests_match(field, &actual, expected) {
+            matched += 1;
+            if !options.quiet {
+                print!("{}: {}\n", path, CHECKSUM_STATUS_OK);
+            }
+        } else {
+            failed += 1;
+            print!("{}: {}\n", path, CHECKSUM_STATUS_FAILED);
+        }
+    }
+
+    if malformed > 0 {
+        eprint!("WARNING: {} line(s) are improperly formatted\n", malformed);
+    }
+    if missing > 0 {
+        eprint!("WARNING: {} listed file(s) could not be read\n", missing);
+    }
+    if failed > 0 {
+        eprint!("WARNING: {} computed checksum(s) did NOT match\n", failed);
+    }
+    if matched + failed == 0 {
+        eprint!("{}: no file was verified\n", list_path);
+    }
+    let success = failed == 0 && missing == 0 && matched > 0;
+    std::process::exit(if success { 0 } else { EXIT_FAILED });
+}
+
+fn glob_matches(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some(&GLOB_ANY) if pattern.get(1) == Some(&GLOB_ANY) => {
+            let rest = &pattern[2..];
+            if rest.first() == Some(&TREE_PATH_SEP) && glob_matches(&rest[1..], text) {
+                return true;
+            }
+            (0..=text.len()).any(|start| glob_matches(rest, &text[start..]))
+        }
+        Some(&GLOB_ANY) => {
+            let mut start = 0;
+            loop {
+                if glob_matches(&pattern[1..], &text[start..]) {
+                    return true;
+                } else if start == text.len() || text[start] == TREE_PATH_SEP {
+                    return false;
+                }
+                start += 1;
+            }
+        }
+        Some(&GLOB_ONE) => match text.first() {
+            Some(&first) => first != TREE_PATH_SEP && glob_matches(&pattern[1..], &text[1..]),
+            None => false,
+        },
+        Some(first) => text.first() == Some(first) && glob_matches(&pattern[1..], &text[1..]),
+    }
+}
+
+fn path_matches(patterns: &Vec<String>, rel_path: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        let subject = match pattern.contains(TREE_PATH_SEP) {
+            true => rel_path,
+            false => rel_path.rsplit(TREE_PATH_SEP).next().unwrap(),
+        };
+        let pattern: Vec<char> = pattern.trim_start_matches(TREE_PATH_SEP).chars().collect();
+        glob_matches(&pattern, &subject.chars().collect::<Vec<char>>())
+    })
+}
+
+fn walk_tree(
+    dir: &std::path::Path,
+    rel_dir: &str,
+    options: &ChecksumOptions,
+    ancestors: &mut Vec<std::path::PathBuf>,
+    entries: &mut Vec<TreeEntry>,
+) {
+    let listing = match std::fs::read_dir(dir) {
+        Ok(listing) => listing,
+        Err(_) => {
+            print!("Could not list `{}`", dir.display());
+            error_out!("Directory error");
+        }
+    };
+    for dir_entry in listing {
+        let dir_entry = match dir_entry {
+            Ok(dir_entry) => dir_entry,
+            Err(_) => error_out!("Error occurred listing a directory"),
+        };
+        let name = match dir_entry.file_name().into_string() {
+            Ok(name) => name,
+            Err(name) => {
+                print!("`{}` in `{}` is not valid UTF-8", name.to_string_lossy(), dir.display());
+                error_out!("Directory trees may only hold UTF-8 names");
+            }
+        };
+        if !options.hidden && name.starts_with(TREE_HIDDEN_PREFIX) {
+            continue;
+        }
+        let rel_path = match rel_dir.is_empty() {
+            true => name,
+            false => format!("{}{}{}", rel_dir, TREE_PATH_SEP, name),
+        };
+        if path_matches(&options.exclude, &rel_path) {
+            continue;
+        }
+        let path = dir_entry.path();
+        let is_symlink = dir_entry.file_type().map(|kind| kind.is_symlink()).unwrap_or(false);
+        let metadata = match is_symlink && !options.follow_symlinks {
+            true => std::fs::symlink_metadata(&path),
+            false => std::fs::metadata(&path),
+        };
+        let metadata = match metadata {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                print!("Could not read `{}`, it may be a broken link", path.display());
+                error_out!("Directory error");
+            }
+        };
+        let included = options.include.is_empty() || path_matches(&options.include, &rel_path);
+        if metadata.is_dir() {
+            let canonical = std::fs::canonicalize(&path).unwrap_or(path.clone());
+            if ancestors.contains(&canonical) {
+                print!("`{}` links back to one of its parents", path.display());
+                error_out!("Directory error");
+            }
+            ancestors.push(canonical);
+            if included {
+                entries.push(TreeEntry {
+                    path: rel_path.clone(),
+                    kind: TREE_KIND_DIR,
+                    digest: pox_hash(&vec![]),
+                });
+            }
+            walk_tree(&path, &rel_path, options, ancestors, entries);
+            ancestors.pop();
+        } else if metadata.file_type().is_symlink() && included {
+            let target = std::fs::read_link(&path).unwrap_or_default();
+            let target = target.to_string_lossy().replace(std::path::MAIN_SEPARATOR, "/");
+            entries.push(TreeEntry {
+                path: rel_path,
+                kind: TREE_KIND_SYMLINK,
+                digest: pox_hash(&target.into_bytes()),
+            });
+        } else if metadata.is_file() && included {
+            let digest = match std::fs::File::open(&path) {
+                Ok(mut file) => hash_reader(&mut file).0,
+                Err(_) => {
+                    print!("Could not open `{}`", path.display());
+                    error_out!("Directory error");
+                }
+            };
+            entries.push(TreeEntry {
+                path: rel_path,
+                kind: TREE_KIND_FILE,
+                digest,
+            });
+        }
+    }
+}
+
+fn get_tree_root(entries: &Vec<TreeEntry>) -> PoxDigest {
+    let manifest: Vec<(&str, u8, [u8; 8])> =
+        entries.iter().map(|entry| (entry.path.as_str(), entry.kind, entry.digest.bytes)).collect();
+    manifest.pox_digest()
+}
+
+fn print_trees(options: &ChecksumOptions, field: &str, inputs: &Vec<String>) {
+    if inputs.is_empty() {
+        error_out!("You must pass at least one directory to `--recursive`");
+    }
+    for input in inputs {
+        let root = std::path::Path::new(input);
+        if !root.is_dir() {
+            print!("`{}` is not a directory", input);
+            error_out!("Directory error");
+        }
+        let mut ancestors = vec![std::fs::canonicalize(root).unwrap_or(root.to_path_buf())];
+        let mut entries = Vec::new();
+        walk_tree(root, "", options, &mut ancestors, &mut entries);
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let prefix = input.trim_end_matches(TREE_PATH_SEP);
+        for entry in entries.iter().filter(|entry| entry.kind == TREE_KIND_FILE) {
+            let path = format!("{}{}{}", prefix, TREE_PATH_SEP, entry.path);
+            let digest = field_to_csv(&entry.digest, field);
+            if options.tag {
+                print!("{}{}{}{}{}\n", CHECKSUM_TAG, CHECKSUM_TAG_OPEN, path, CHECKSUM_TAG_CLOSE, digest);
+            } else {
+                print!("{}{}{}\n", digest, CHECKSUM_SEP, path);
+            }
+        }
+        let root_digest = field_to_csv(&get_tree_root(&entries), field);
+        print!("{}{}{}{}{}\n", TREE_TAG, CHECKSUM_TAG_OPEN, input, CHECKSUM_TAG_CLOSE, root_digest);
+    }
+    std::process::exit(0);
+}
+
+fn run_checksums(options: &ChecksumOptions, flags: &String, inputs: &Vec<String>) {
+    let field = get_checksum_field(&format!("{}{}{}", FLAG_DASH, flags, FLAG_DASH));
+    if options.has_tree_options() && !options.recursive {
+        error_out!("`--include`, `--exclude`, `--follow-symlinks` and `--hidden` only apply to `--recursive`");
+    }
+    match options.check {
+        Some(ref list_path) => {
+            if options.generate || options.tag || options.recursive {
+                error_out!("You may not pass `--sum`, `--tag` or `--recursive` along with `--check`");
+            } else if !inputs.is_empty() {
+                error_out!("`--check` reads the files to hash from the checksum file");
+            }
+            verify_checksums(options, field, list_path);
+        }
+        None => {
+            if options.quiet || options.ignore_missing {
+                error_out!("`--quiet` and `--ignore-missing` only apply to `--check`");
+            } else if options.recursive {
+                print_trees(options, field, inputs);
+            }
+            print_checksums(options, field, inputs);
+        }
+    }
+}
+
+#[derive(Default)]
+struct BulkOptions {
+    lines: bool,
+    keep_newlines: bool,
+    crlf: bool,
+    skip_empty: bool,
+    files_from: Option<String>,
+    null: bool,
+}
+
+impl BulkOptions {
+    fn is_requested(&self) -> bool {
+        self.lines || self.keep_newlines || self.crlf || self.skip_empty || self.files_from.is_some() || self.null
+    }
+}
+
+fn open_bulk_source(path: &String) -> Box<dyn std::io::BufRead> {
+    if path == STDIN_DASH {
+        return Box::new(std::io::stdin().lock());
+    }
+    match open_regular_file(path) {
+        Some(file) => Box::new(std::io::BufReader::new(file)),
+        None => {
+            print!("`{}` does not exist or is a directory", path);
+            error_out!("Input error");
+        }
+    }
+}
+
+fn read_bulk_entry(reader: &mut Box<dyn std::io::BufRead>, separator: u8, entry: &mut Vec<u8>) -> bool {
+    entry.clear();
+    match reader.read_until(separator, entry) {
+        Ok(read) => read > 0,
+        Err(_) => error_out!("Error occurred reading the input"),
+    }
+}
+
+// Lengths of the line without its terminator, for the label, and of the bytes to hash
+fn get_line_lengths(options: &BulkOptions, line: &[u8]) -> Option<(usize, usize)> {
+    let mut content_len = line.len();
+    if line.last() == Some(&b'\n') {
+        content_len -= 1;
+        if options.crlf && content_len > 0 && line[content_len - 1] == b'\r' {
+            content_len -= 1;
+        }
+    }
+    if options.skip_empty && content_len == 0 {
+        return None;
+    }
+    Some((content_len, if options.keep_newlines { line.len() } else { content_len }))
+}
+
+fn hash_lines(options: &BulkOptions, writer: &mut RecordWriter, inputs: &Vec<String>) {
+    let sources = match inputs.is_empty() {
+        true => vec![STDIN_DASH.to_string()],
+        false => inputs.clone(),
+    };
+    let mut line = Vec::new();
+    for source in sources.iter() {
+        let mut reader = open_bulk_source(source);
+        while read_bulk_entry(&mut reader, b'\n', &mut line) {
+            let (content_len, message_len) = match get_line_lengths(options, &line) {
+                Some(lengths) => lengths,
+                None => continue,
+            };
+            line.truncate(message_len);
+            writer.write(&Record {
+                source: SOURCE_LINE,
+                label: String::from_utf8_lossy(&line[..content_len]).to_string(),
+                length: line.len() as u64,
+                digest: pox_hash(&line),
+                time: 0,
+            });
+        }
+    }
+}
+
+fn hash_files_from(options: &BulkOptions, writer: &mut RecordWriter, list_path: &String) -> usize {
+    let separator = if options.null { b'\0' } else { b'\n' };
+    let mut reader = open_bulk_source(list_path);
+    let mut entry = Vec::new();
+    let mut missing = 0usize;
+    while read_bulk_entry(&mut reader, separator, &mut entry) {
+        if entry.last() == Some(&separator) {
+            entry.pop();
+        }
+        if entry.is_empty() {
+            continue;
+        }
+        let path = String::from_utf8_lossy(&entry).to_string();
+        match open_regular_file(path_from_bytes(&entry)) {
+            Some(mut file) => {
+                let (digest, length) = hash_reader(&mut file);
+                writer.write(&Record {
+                    source: SOURCE_FILE,
+                    label: path,
+                    length,
+                    digest,
+                    time: 0,
+                });
+            }
+            None => {
+                eprint!("{}: {}\n", path, CHECKSUM_STATUS_MISSING);
+                missing += 1;
+            }
+        }
+    }
+    missing
+}
+
+fn run_bulk(options: &BulkOptions, flag_word: &String, inputs: &Vec<String>) {
+    if [FLAG_ECHO, FLAG_JOIN, FLAG_BENCHMARK].iter().any(|flag| arg_has_flag(flag_word, *flag)) {
+        error_out!("`--lines` and `--files-from` can't be combined with `--echo`, `--join` or `--benchmark`");
+    } else if options.lines == options.files_from.is_some() {
+        error_out!("Pass either `--lines` or `--files-from`");
+    } else if options.files_from.is_some() && (options.keep_newlines || options.crlf || options.skip_empty) {
+        error_out!("`--keep-newlines`, `--crlf` and `--skip-empty` only apply to `--lines`");
+    } else if options.lines && options.null {
+        error_out!("`--null` only applies to `--files-from`");
+    } else if options.files_from.is_some() && !inputs.is_empty() {
+        error_out!("`--files-from` reads the files to hash from its list and takes no inputs");
+    } else if inputs.iter().filter(|path| *path == STDIN_DASH).count() > 1 {
+        error_out!("Standard input can only be hashed once");
+    } else if get_output_mode(flag_word) == '\0' && get_requested_fields(flag_word).len() != 1 {
+        error_out!("Plain `--lines` and `--files-from` output takes one digest option, use `--jsonl` or `--csv` for more");
+    }
+
+    let mut writer = RecordWriter::new(flag_word);
+    writer.begin();
+    let missing = match options.files_from {
+        Some(ref list_path) => hash_files_from(options, &mut writer, list_path),
+        None => {
+            hash_lines(options, &mut writer, inputs);
+            0
+        }
+    };
+    writer.finish();
+    std::process::exit(if missing > 0 { EXIT_FAILED } else { 0 });
+}
+
+fn translate_long_options(argv: &Vec<String>) -> (Vec<String>, Option<String>) {
+    let exec_name = get_exec_name(argv.get(0).unwrap());
+    let mut flags = String::new();
+    let mut inputs = Vec::new();
+    let mut options_ended = false;
+    let mut checksums = ChecksumOptions::default();
+    let mut expected = None;
+    let mut bulk = BulkOptions::default();
+    let mut args = argv[1..].iter();
+    while let Some(arg) = args.next() {
+        if options_ended || !arg.starts_with(LONG_PREFIX) {
+            inputs.push(arg.clone());
+            continue;
+        } else if arg == LONG_PREFIX {
+            options_ended = true;
+            continue;
+        }
+        let (name, mut value) = match arg.find(LONG_VALUE_SEP) {
+            Some(index) => (&arg[LONG_PREFIX.len()..index], Some(&arg[index + 1..])),
+            None => (&arg[LONG_PREFIX.len()..], None),
+        };
+        let option = find_long_option(LONG_OPTIONS, name);
+        if value.is_none() && option.value_required {
+            value = args.next().map(|next| next.as_str());
+        }
+        let values = check_option_value(option, value);
+        match option.name {
+            OPTION_VERSION => print_version(),
+            OPTION_COMPLETIONS => print_completions(&values[0], &exec_name),
+            OPTION_SUM => checksums.generate = true,
+            OPTION_TAG => checksums.tag = true,
+            OPTION_CHECK => checksums.check = Some(values[0].clone()),
+            OPTION_QUIET => checksums.quiet = true,
+            OPTION_IGNORE_MISSING => checksums.ignore_missing = true,
+            OPTION_RECURSIVE => checksums.recursive = true,
+            OPTION_INCLUDE => checksums.include.push(values[0].clone()),
+            OPTION_EXCLUDE => checksums.exclude.push(values[0].clone()),
+            OPTION_FOLLOW_SYMLINKS => checksums.follow_symlinks = true,
+            OPTION_HIDDEN => checksums.hidden = true,
+            OPTION_EXPECT => expected = Some(values[0].clone()),
+            OPTION_LINES => bulk.lines = true,
+            OPTION_KEEP_NEWLINES => bulk.keep_newlines = true,
+            OPTION_CRLF => bulk.crlf = true,
+            OPTION_SKIP_EMPTY => bulk.skip_empty = true,
+            OPTION_FILES_FROM => bulk.files_from = Some(values[0].clone()),
+            OPTION_NULL => bulk.null = true,
+            _ if option.flag == FLAG_HELP => print_help(exec_name.clone()),
+            _ => {}
+        }
+        if option.flag == LONG_ONLY {
+            continue;
+        } else if !flags.contains(option.flag) {
+            flags.push(option.flag);
+        }
+        for value in values {
+            let flag = match BENCHMARK_UNITS.iter().find(|(unit, _)| *unit == value) {
+                Some((_, flag)) => *flag,
+                None => FLAG_BENCHMARK,
+            };
+            if value == BENCHMARK_ONLY || !flags.contains(flag) {
+                flags.push(flag);
+            }
+        }
+    }
+    if !flags.contains(|flag| DIGEST_FLAGS.contains(&flag)) {
+        flags.push(DEFAULT_DIGEST_FLAG);
+    }
+    if bulk.is_requested() {
+        if checksums.is_requested() || expected.is_some() {
+            error_out!("`--lines` and `--files-from` can't be combined with checksum options or `--expect`");
+        }
+        let flag_word = format!("{}{}{}", FLAG_DASH, flags, FLAG_DASH);
+        validate_flags(&vec![argv.get(0).unwrap().clone(), flag_word.clone()], false);
+        run_bulk(&bulk, &flag_word, &inputs);
+    }
+    if checksums.is_requested() {
+        if expected.is_some() {
+            error_out!("`--expect` does not apply to checksum files or directory trees");
+        }
+        run_checksums(&checksums, &flags, &inputs);
+    }
+    if inputs.is_empty() {
+        error_out!("You must pass at least one argument to hash");
+    }
+    if let Some(ref expected) = expected {
+        if inputs.len() > 1 && !flags.contains(FLAG_JOIN) {
+            error_out!("`--expect` takes exactly one input, or several with `--join`");
+        } else if flags.matches(FLAG_BENCHMARK).count() > 1 {
+            error_out!("`--expect` needs a digest, so it can't be combined with `--benchmark=only`");
+        } else if decode_expected(expected).is_empty() {
+            print!("`{}` is not a digest in any non-decimal base", expected);
+            error_out!("Option error");
+        }
+    }
+
+    let mut translated = vec![argv.get(0).unwrap().clone(), format!("{}{}{}", FLAG_DASH, flags, FLAG_DASH)];
+    translated.extend(inputs);
+    (translated, expected)
+}
+
+fn get_radix_encoding(field: &str) -> RadixEncoding {
+    match field {
+        "sexdigest" => RadixEncoding::SEX,
+        "vigdigest" => RadixEncoding::VIG,
+        "hexdigest" => RadixEncoding::HEX,
+        "tetdigest" => RadixEncoding::TET,
+        "duodigest" => RadixEncoding::DUO,
+        "octdigest" => RadixEncoding::OCT,
+        "sendigest" => RadixEncoding::SEN,
+        _ => RadixEncoding::BIN,
+    }
+}
+
+fn decode_expected(expected: &str) -> Vec<(&'static str, PoxDigest)> {
+    FIELD_NAMES
+        .iter()
+        .filter(|field| field.ends_with("digest"))
+        .filter_map(|field| {
+            let encoded = match *field {
+                "hexdigest" => expected.to_ascii_uppercase(),
+                _ => expected.to_string(),
+            };
+            get_radix_encoding(field).decode(&encoded).ok().map(|digest| (*field, digest))
+        })
+        .collect()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let difference = a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    a.len() == b.len() && difference == 0
+}
+
+fn verify_expected(expected: &String, digest: &PoxDigest, machine_readable: bool) -> i32 {
+    let candidates = decode_expected(expected);
+    let matched = candidates
+        .iter()
+        .fold(false, |acc, (_, candidate)| acc | constant_time_eq(&candidate.bytes, &digest.bytes));
+    let mut verdict = format!("Expected: {}\n", expected);
+    for (field, _) in candidates.iter() {
+        verdict.push_str(&format!("Actual:   {} ({})\n", field_to_csv(digest, field), field));
+    }
+    verdict.push_str(match (matched, machine_readable) {
+        (true, true) => "Verdict:  MATCH\n",
+        (false, true) => "Verdict:  MISMATCH\n",
+        (true, false) => "Verdict:  \x1b[1;32mMATCH\x1b[0m\n",
+        (false, false) => "Verdict:  \x1b[1;31mMISMATCH\x1b[0m\n",
+    });
+    match machine_readable {
+        true => eprint!("{}", verdict),
+        false => print!("{}", verdict),
+    }
+    if matched { 0 } else { EXIT_FAILED }
+}
+
 fn get_exec_name(argv0: &String) -> String {
     let argv0_split = argv0.split("/");
     argv0_split.last().unwrap().to_string()
@@ -318,7 +1500,7 @@ fn search_for_flag_reocurrance(flags_arg: &String) -> char {
     '\0'
 }
 
-fn validate_flags(argv: &Vec<String>) {
+fn validate_flags(argv: &Vec<String>, legacy: bool) {
     let num_argv = argv.len();
 
     if num_argv < MIN_ARG_NUM - 1 {
@@ -329,7 +1511,7 @@ fn validate_flags(argv: &Vec<String>) {
     let flags_arg = argv.get(1).unwrap();
 
     let len_flags = flags_arg.len();
-    if len_flags < MIN_FLAG_SIZE || len_flags > MAX_FLAG_SIZE {
+    if legacy && (len_flags < MIN_FLAG_SIZE || len_flags > MAX_FLAG_SIZE) {
         error_out!("Length of the first argument must at least be 3 and at most 24");
     }
 
@@ -364,10 +1546,22 @@ fn validate_flags(argv: &Vec<String>) {
     let all_flags_dec_passed = arg_has_flag(flags_arg, FLAG_ALL_DECIMAL);
     let all_flags_nondec_passed = arg_has_flag(flags_arg, FLAG_ALL_NON_DEC);
     let benchmark_has_passed = arg_has_flag(flags_arg, FLAG_BENCHMARK);
+    let output_modes_passed = [FLAG_JSON, FLAG_JSONL, FLAG_CSV]
+        .iter()
+        .filter(|mode| arg_has_flag(flags_arg, **mode))
+        .count();
 
     for flag in flags_arg[1..len_flags - 1].chars() {
         match flag {
             FLAG_BENCHMARK | FLAG_JOIN | FLAG_NHEADER | FLAG_ECHO => continue,
+            FLAG_JSON | FLAG_JSONL | FLAG_CSV => {
+                if output_modes_passed > 1 {
+                    error_out!("You may only pass one of `j`, `l` and `c`");
+                } else if arg_has_flag(flags_arg, FLAG_ECHO) {
+                    error_out!("You may not pass `e` along with `j`, `l` or `c`");
+                }
+                continue;
+            }
             FLAG_NS | FLAG_US | FLAG_MS | FLAG_SS | FLAG_MM => {
                 if !benchmark_has_passed {
                     error_out!("When a timestamp flag has passed, `^` must be passed as well");
@@ -478,10 +1672,8 @@ fn validate_flags(argv: &Vec<String>) {
 }
 
 fn get_time_in_ns() -> u128 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .expect("Error getting time")
-        .as_nanos()
+    static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    START.get_or_init(std::time::Instant::now).elapsed().as_nanos()
 }
 
 fn convert_time(time: u128, divisor: u128) -> String {
@@ -528,7 +1720,7 @@ fn print_hashes(hashes: &Vec<PoxDigest>, flags: &String, total_time: u128) {
 
     if double_benchmark {
         println!();
-        std::process::exit(0);
+        return;
     }
 
     let everything = arg_has_flag(flags, FLAG_EVERTHING);
@@ -567,7 +1759,7 @@ fn print_hashes(hashes: &Vec<PoxDigest>, flags: &String, total_time: u128) {
 
     if all_false {
         print!("You had not specfied any digests to be printed\n");
-        std::process::exit(0);
+        return;
     }
 
     for hash in hashes.into_iter() {
@@ -624,50 +1816,336 @@ fn print_hashes(hashes: &Vec<PoxDigest>, flags: &String, total_time: u128) {
     }
 }
 
+struct Record {
+    source: &'static str,
+    label: String,
+    length: u64,
+    digest: PoxDigest,
+    time: u128,
+}
+
+fn get_output_mode(flags: &String) -> char {
+    for mode in [FLAG_JSON, FLAG_JSONL, FLAG_CSV].iter() {
+        if arg_has_flag(flags, *mode) {
+            return *mode;
+        }
+    }
+    '\0'
+}
+
+fn get_requested_fields(flags: &String) -> Vec<&'static str> {
+    let everything = arg_has_flag(flags, FLAG_EVERTHING);
+    let all_flags_decimal = everything || arg_has_flag(flags, FLAG_ALL_DECIMAL);
+    let all_flags_non_decimal = everything || arg_has_flag(flags, FLAG_ALL_NON_DEC);
+    let field_flags = [
+        (FLAG_BYTES, all_flags_decimal),
+        (FLAG_WORDS, all_flags_decimal),
+        (FLAG_DOUBLES, all_flags_decimal),
+        (FLAG_QUAD, all_flags_decimal),
+        (FLAG_SEX, all_flags_non_decimal),
+        (FLAG_VIG, all_flags_non_decimal),
+        (FLAG_HEX, all_flags_non_decimal),
+        (FLAG_TET, all_flags_non_decimal),
+        (FLAG_DUO, all_flags_non_decimal),
+        (FLAG_OCT, all_flags_non_decimal),
+        (FLAG_SEN, all_flags_non_decimal),
+        (FLAG_BIN, all_flags_non_decimal),
+    ];
+    FIELD_NAMES
+        .iter()
+        .zip(field_flags.iter())
+        .filter(|(_, (flag, all))| *all || arg_has_flag(flags, *flag))
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+fn join_numbers<T: ToString>(numbers: &[T], sep: &str) -> String {
+    numbers.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(sep)
+}
+
+fn to_json_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn to_csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn field_to_json(digest: &PoxDigest, name: &str) -> String {
+    match name {
+        "bytes" => format!("[{}]", join_numbers(&digest.bytes, ", ")),
+        "words" => format!("[{}]", join_numbers(&digest.words, ", ")),
+        "doubles" => format!("[{}]", join_numbers(&digest.doubles, ", ")),
+        "quad" => to_json_string(&digest.quad.to_string()),
+        _ => to_json_string(&field_to_csv(digest, name)),
+    }
+}
+
+fn field_to_csv(digest: &PoxDigest, name: &str) -> String {
+    match name {
+        "bytes" => join_numbers(&digest.bytes, CSV_LIST_SEP),
+        "words" => join_numbers(&digest.words, CSV_LIST_SEP),
+        "doubles" => join_numbers(&digest.doubles, CSV_LIST_SEP),
+        "quad" => digest.quad.to_string(),
+        "sexdigest" => digest.sexdigest.clone(),
+        "vigdigest" => digest.vigdigest.clone(),
+        "hexdigest" => digest.hexdigest.clone(),
+        "tetdigest" => digest.tetdigest.clone(),
+        "duodigest" => digest.duodigest.clone(),
+        "octdigest" => digest.octdigest.clone(),
+        "sendigest" => digest.sendigest.clone(),
+        _ => digest.bindigest.clone(),
+    }
+}
+
+fn record_to_json(record: &Record, fields: &Vec<&'static str>, timed: bool) -> String {
+    let mut members = vec![
+        format!("\"source\": {}", to_json_string(record.source)),
+        format!("\"label\": {}", to_json_string(&record.label)),
+        format!("\"length\": {}", record.length),
+    ];
+    for name in fields {
+        members.push(format!("\"{}\": {}", name, field_to_json(&record.digest, name)));
+    }
+    if timed {
+        members.push(format!("\"{}\": {}", FIELD_TIME, record.time));
+    }
+    format!("{{{}}}", members.join(", "))
+}
+
+fn record_to_csv(record: &Record, fields: &Vec<&'static str>, timed: bool) -> String {
+    let mut columns = vec![
+        record.source.to_string(),
+        to_csv_field(&record.label),
+        record.length.to_string(),
+    ];
+    for name in fields {
+        columns.push(to_csv_field(&field_to_csv(&record.digest, name)));
+    }
+    if timed {
+        columns.push(record.time.to_string());
+    }
+    columns.join(",")
+}
+
+struct RecordWriter {
+    mode: char,
+    fields: Vec<&'static str>,
+    timed: bool,
+    written: usize,
+}
+
+impl RecordWriter {
+    fn new(flags: &String) -> RecordWriter {
+        let reoccurrance = search_for_flag_reocurrance(&flags[1..flags.len() - 1].to_string());
+        RecordWriter {
+            mode: get_output_mode(flags),
+            fields: if reoccurrance == FLAG_BENCHMARK { vec![] } else { get_requested_fields(flags) },
+            timed: arg_has_flag(flags, FLAG_BENCHMARK),
+            written: 0,
+        }
+    }
+
+    fn begin(&self) {
+        if self.mode == FLAG_CSV {
+            let mut header = vec!["source", "label", "length"];
+            header.extend(self.fields.iter());
+            if self.timed {
+                header.push(FIELD_TIME);
+            }
+            println!("{}", header.join(","));
+        }
+    }
+
+    fn write(&mut self, record: &Record) {
+        match self.mode {
+            FLAG_JSON => {
+                let sep = if self.written == 0 { "[\n" } else { ",\n" };
+                print!("{}  {}", sep, record_to_json(record, &self.fields, self.timed));
+            }
+            FLAG_JSONL => println!("{}", record_to_json(record, &self.fields, self.timed)),
+            FLAG_CSV => println!("{}", record_to_csv(record, &self.fields, self.timed)),
+            _ => {
+                let digests = self
+                    .fields
+                    .iter()
+                    .map(|name| field_to_csv(&record.digest, name))
+                    .collect::<Vec<String>>();
+                print!("{}{}{}\n", digests.join(" "), CHECKSUM_SEP, record.label);
+            }
+        }
+        self.written += 1;
+    }
+
+    fn finish(&self) {
+        if self.mode == FLAG_JSON {
+            match self.written {
+                0 => println!("[]"),
+                _ => println!("\n]"),
+            }
+        }
+    }
+}
+
+fn print_records(records: &Vec<Record>, flags: &String) {
+    let mut writer = RecordWriter::new(flags);
+    writer.begin();
+    for record in records {
+        writer.write(record);
+    }
+    writer.finish();
+}
+
 fn assert_file(arg: &String) -> bool {
     arg.len() > FILE_PREFIX_LEN && arg.starts_with(FILE_PREFIX)
 }
 
+fn assert_stdin(arg: &String) -> bool {
+    arg == STDIN_DASH || arg == STDIN_NAME
+}
+
 fn assert_int(arg: &String) -> bool {
     arg.len() > INT_PREFIX_LEN && arg.starts_with(INT_PREFIX)
 }
 
-fn to_int(arg: &String) -> Vec<u8> {
-    arg.split(",")
-        .map(|n| {
-            let sans_prefix = &n[BASE_PREFIX_NUM..];
-            match &n[..BASE_PREFIX_NUM] {
-                BIN_PREFIX => {
-                    if sans_prefix.len() > MAX_BIN {
-                        error_out!("Size of binary number should not exceed 8");
-                    }
-                    
-                    u8::from_str_radix(sans_prefix, 2).expect("Bad binary number")
-                },
-                OCT_PREFIX => {
-                    if sans_prefix.len() > MAX_OCT {
-                        error_out!("Size of octal number should not exceed 5");
-                    }
-
-                    u8::from_str_radix(sans_prefix, 8).expect("Bad octal number")
-                },
-                HEX_PREFIX => {
-                    if sans_prefix.len() > MAX_HEX {
-                        error_out!("Size of hexadecimal number should not exceed 2");
-                    }
-
-                    u8::from_str_radix(sans_prefix, 16).expect("Bad hexadecimal number")
-                },
-                _ => {
-                    if n.to_string().chars().any(|c| !c.is_numeric()) {
-                        error_out!("With 'int=' prefix you must pass byte-sized integers in base 16, 8, 10 and 2");
-                    }
-
-                    u8::from_str_radix(n, 10).expect("Given integer must be byte-sized (0-255)")
+fn assert_gen(arg: &String) -> bool {
+    arg.len() > GEN_PREFIX_LEN && arg.starts_with(GEN_PREFIX)
+}
+
+fn assert_hex(arg: &String) -> bool {
+    arg.len() > HEX_INPUT_PREFIX_LEN && arg.starts_with(HEX_INPUT_PREFIX)
+}
+
+fn assert_b64(arg: &String) -> bool {
+    arg.len() > B64_INPUT_PREFIX_LEN && arg.starts_with(B64_INPUT_PREFIX)
+}
+
+fn get_typed_prefix(arg: &String) -> Option<&'static (&'static str, usize, bool)> {
+    TYPED_PREFIXES
+        .iter()
+        .find(|(prefix, _, _)| arg.len() > prefix.len() && arg.starts_with(prefix))
+}
+
+fn parse_int_token(token: &str, bits: usize) -> Result<u64, String> {
+    let (digits, radix) = match token.get(..BASE_PREFIX_NUM) {
+        Some(BIN_PREFIX) => (&token[BASE_PREFIX_NUM..], 2),
+        Some(OCT_PREFIX) => (&token[BASE_PREFIX_NUM..], 8),
+        Some(HEX_PREFIX) => (&token[BASE_PREFIX_NUM..], 16),
+        _ => (token, 10),
+    };
+    if digits.is_empty() {
+        return Err("has no digits".to_string());
+    } else if let Some(bad) = digits.chars().find(|c| !c.is_digit(radix)) {
+        return Err(format!("has `{}`, which is not a base {} digit", bad, radix));
+    }
+    match u64::from_str_radix(digits, radix) {
+        Ok(value) if bits == 64 || value >> bits == 0 => Ok(value),
+        _ => Err(format!("does not fit in {} bits", bits)),
+    }
+}
+
+fn to_int(arg: &String, prefix_len: usize, width: usize, big_endian: bool) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for (index, token) in arg[prefix_len..].split(INT_LIST_SEP).enumerate() {
+        let token = token.trim();
+        match parse_int_token(token, width * 8) {
+            Ok(value) => {
+                let le_bytes = value.to_le_bytes();
+                match big_endian {
+                    true => bytes.extend(le_bytes[..width].iter().rev()),
+                    false => bytes.extend_from_slice(&le_bytes[..width]),
                 }
             }
-        })
-        .collect()
+            Err(reason) => {
+                print!("Value {} (`{}`) of `{}` {}", index + 1, token, arg, reason);
+                error_out!("Input error");
+            }
+        }
+    }
+    bytes
+}
+
+fn to_hex_bytes(arg: &String) -> Vec<u8> {
+    let mut nibbles = Vec::new();
+    for (index, c) in arg[HEX_INPUT_PREFIX_LEN..].chars().enumerate() {
+        if c.is_whitespace() || c == HEX_INPUT_SEP {
+            continue;
+        }
+        match c.to_digit(16) {
+            Some(nibble) => nibbles.push(nibble as u8),
+            None => {
+                print!("`{}` at position {} of `{}` is not a hex digit", c, index + 1, arg);
+                error_out!("Input error");
+            }
+        }
+    }
+    if !nibbles.len().is_multiple_of(2) {
+        print!("`{}` has an odd number of hex digits, {}", arg, nibbles.len());
+        error_out!("Input error");
+    }
+    nibbles.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect()
+}
+
+fn to_b64_bytes(arg: &String) -> Vec<u8> {
+    let mut sextets = Vec::new();
+    let mut padding = 0usize;
+    for (index, c) in arg[B64_INPUT_PREFIX_LEN..].chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let sextet = match c {
+            'A'..='Z' => c as u8 - b'A',
+            'a'..='z' => c as u8 - b'a' + 26,
+            '0'..='9' => c as u8 - b'0' + 52,
+            '+' | '-' => 62,
+            '/' | '_' => 63,
+            B64_PAD => {
+                padding += 1;
+                continue;
+            }
+            _ => {
+                print!("`{}` at position {} of `{}` is not a base64 character", c, index + 1, arg);
+                error_out!("Input error");
+            }
+        };
+        if padding > 0 {
+            print!("`{}` at position {} of `{}` comes after the `{}` padding", c, index + 1, arg, B64_PAD);
+            error_out!("Input error");
+        }
+        sextets.push(sextet as u32);
+    }
+    if sextets.len() % 4 == 1 || (padding > 0 && !(sextets.len() + padding).is_multiple_of(4)) {
+        print!("`{}` has {} base64 characters and {} padding, which no bytes encode to", arg, sextets.len(), padding);
+        error_out!("Input error");
+    }
+    let (mut bytes, mut buffer, mut bits) = (Vec::new(), 0u32, 0u32);
+    for sextet in sextets {
+        buffer = (buffer << 6) | sextet;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    bytes
 }
 
 fn join_args(argv: &Vec<String>) -> String {
@@ -685,33 +2163,646 @@ fn is_regular_file(fpath: &String) {
     }
 }
 
-fn read_given_file(fpath: &String) -> String {
+fn hash_reader<R: std::io::Read>(reader: &mut R) -> (PoxDigest, u64) {
+    let mut hasher = PoxHasher::new();
+    match std::io::copy(reader, &mut hasher) {
+        Ok(length) => (hasher.finalize(), length),
+        Err(_) => error_out!("Error occurred reading the input"),
+    }
+}
+
+fn hash_given_file(fpath: &String) -> (PoxDigest, u64) {
     is_regular_file(fpath);
-    std::fs::read_to_string(fpath).expect("Unkown error occrurred reading file")
+    match std::fs::File::open(fpath) {
+        Ok(mut file) => hash_reader(&mut file),
+        Err(_) => error_out!("Specified file could not be opened"),
+    }
+}
+
+enum GenKind {
+    Zeros,
+    Rand(SplitMix64),
+    Repeat(Vec<u8>),
+    Counter,
+}
+
+struct GenReader {
+    kind: GenKind,
+    position: u64,
+    length: u64,
+    word: u64,
+}
+
+impl GenReader {
+    fn new(kind: GenKind, length: u64) -> GenReader {
+        GenReader {
+            kind,
+            position: 0,
+            length,
+            word: 0,
+        }
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        let byte = match self.kind {
+            GenKind::Zeros => 0,
+            GenKind::Counter => self.position as u8,
+            GenKind::Repeat(ref pattern) => pattern[(self.position % pattern.len() as u64) as usize],
+            GenKind::Rand(ref mut rng) => {
+                if self.position.is_multiple_of(8) {
+                    self.word = rng.next_u64();
+                }
+                (self.word >> (8 * (self.position % 8))) as u8
+            }
+        };
+        self.position += 1;
+        byte
+    }
+}
+
+impl std::io::Read for GenReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = std::cmp::min(buf.len() as u64, self.length - self.position) as usize;
+        for byte in buf[..count].iter_mut() {
+            *byte = self.next_byte();
+        }
+        Ok(count)
+    }
 }
 
-fn process_arg(arg: &String) -> Vec<u8> {
-    if !assert_file(arg) && !assert_int(arg) {
-        return arg.clone().as_bytes().to_vec();
+fn parse_gen_number(number: &str, spec: &str) -> u64 {
+    let (digits, multiplier) = match number.chars().last().map(|last| last.to_ascii_uppercase()) {
+        Some(last) => match SIZE_SUFFIXES.iter().find(|(suffix, _)| *suffix == last) {
+            Some((_, multiplier)) => (&number[..number.len() - 1], *multiplier),
+            None => (number, 1),
+        },
+        None => (number, 1),
+    };
+    match digits.parse::<u64>().ok().and_then(|digits| digits.checked_mul(multiplier)) {
+        Some(parsed) => parsed,
+        None => {
+            print!("`{}` in `{}{}` is not a number, sizes may end in K, M or G", number, GEN_PREFIX, spec);
+            error_out!("Generator error");
+        }
+    }
+}
+
+fn parse_gen_spec(spec: &str) -> GenReader {
+    let (name, args) = spec.split_once(GEN_SEP).unwrap_or((spec, ""));
+    let parsed = match name {
+        GEN_ZEROS if !args.contains(GEN_SEP) => Some((GenKind::Zeros, args)),
+        GEN_COUNTER if !args.contains(GEN_SEP) => Some((GenKind::Counter, args)),
+        GEN_RAND => args
+            .split_once(GEN_SEP)
+            .map(|(seed, size)| (GenKind::Rand(SplitMix64::new(parse_gen_number(seed, spec))), size)),
+        GEN_REPEAT => args
+            .rsplit_once(GEN_SEP)
+            .filter(|(pattern, _)| !pattern.is_empty())
+            .map(|(pattern, size)| (GenKind::Repeat(pattern.as_bytes().to_vec()), size)),
+        _ => None,
+    };
+    match parsed {
+        Some((kind, size)) if !size.is_empty() => GenReader::new(kind, parse_gen_number(size, spec)),
+        _ => {
+            print!("`{}{}` is not a generator, use one of: {}", GEN_PREFIX, spec, GEN_USAGE);
+            error_out!("Generator error");
+        }
+    }
+}
+
+fn hash_stream(arg: &String) -> (PoxDigest, u64) {
+    if assert_stdin(arg) {
+        return hash_reader(&mut std::io::stdin().lock());
+    } else if assert_gen(arg) {
+        return hash_reader(&mut parse_gen_spec(&arg[GEN_PREFIX_LEN..]));
+    }
+    hash_given_file(&arg[FILE_PREFIX_LEN..].to_string())
+}
+
+fn get_source(arg: &String) -> (&'static str, String) {
+    if assert_stdin(arg) {
+        (SOURCE_STDIN, STDIN_NAME.to_string())
+    } else if assert_file(arg) {
+        (SOURCE_FILE, arg[FILE_PREFIX_LEN..].to_string())
+    } else if assert_int(arg) {
+        (SOURCE_INT, arg[INT_PREFIX_LEN..].to_string())
+    } else if assert_gen(arg) {
+        (SOURCE_GEN, arg[GEN_PREFIX_LEN..].to_string())
+    } else if assert_hex(arg) {
+        (SOURCE_HEX, arg[HEX_INPUT_PREFIX_LEN..].to_string())
+    } else if assert_b64(arg) {
+        (SOURCE_B64, arg[B64_INPUT_PREFIX_LEN..].to_string())
+    } else if let Some((prefix, _, _)) = get_typed_prefix(arg) {
+        (&prefix[..prefix.len() - 1], arg[prefix.len()..].to_string())
+    } else {
+        (SOURCE_ARG, arg.clone())
+    }
+}
+
+fn process_arg(arg: &String) -> Option<Vec<u8>> {
+    if assert_stdin(arg) || assert_file(arg) || assert_gen(arg) {
+        return None;
     } else if assert_int(arg) {
-        return to_int(&arg[INT_PREFIX_LEN..].to_string());
+        return Some(to_int(arg, INT_PREFIX_LEN, 1, false));
+    } else if assert_hex(arg) {
+        return Some(to_hex_bytes(arg));
+    } else if assert_b64(arg) {
+        return Some(to_b64_bytes(arg));
+    } else if let Some((prefix, width, big_endian)) = get_typed_prefix(arg) {
+        return Some(to_int(arg, prefix.len(), *width, *big_endian));
+    }
+    Some(arg.clone().as_bytes().to_vec())
+}
+
+struct BenchSettings {
+    iterations: Option<usize>,
+    time: Option<f64>,
+    warmup: usize,
+    save: Option<String>,
+    compare: Option<String>,
+    sweep: Option<usize>,
+    scale: String,
+    steps: usize,
+    csv: Option<String>,
+    ascii: bool,
+}
+
+struct BenchStats {
+    label: String,
+    length: u64,
+    samples: usize,
+    min: f64,
+    median: f64,
+    mean: f64,
+    stddev: f64,
+    p99: f64,
+}
+
+fn print_bench_help(exec: &String) {
+    print!("Usage: {} {} [OPTION]... INPUT...\n", exec, SUBCOMMAND_BENCH);
+    print!("Times every input many times over and prints min, median, mean, stddev, p99 and throughput\n");
+    print!("Inputs take the same prefixes as when hashing, such as `{}`, `{}`, `{}` and `{}`\n\n", FILE_PREFIX, HEX_INPUT_PREFIX, GEN_PREFIX, STDIN_DASH);
+    for option in BENCH_OPTIONS {
+        print!("\x1b[1;33m\t{}\x1b[0m: {}\n", option.usage(), option.help);
+    }
+    print!("Without `{}{}` or `{}{}`, each input is timed for {} second\n\n", LONG_PREFIX, BENCH_ITERATIONS, LONG_PREFIX, BENCH_TIME, BENCH_DEFAULT_TIME);
+    std::process::exit(0);
+}
+
+fn parse_bench_number<T: std::str::FromStr>(name: &str, value: &String) -> T {
+    match value.parse::<T>() {
+        Ok(number) => number,
+        Err(_) => {
+            print!("`{}` is not a valid value for `{}{}`", value, LONG_PREFIX, name);
+            error_out!("Option error");
+        }
+    }
+}
+
+fn parse_bench_args(argv: &Vec<String>) -> (BenchSettings, Vec<String>) {
+    let exec_name = get_exec_name(argv.get(0).unwrap());
+    let mut settings = BenchSettings {
+        iterations: None,
+        time: None,
+        warmup: BENCH_DEFAULT_WARMUP,
+        save: None,
+        compare: None,
+        sweep: None,
+        scale: SCALE_LOG.to_string(),
+        steps: SWEEP_DEFAULT_STEPS,
+        csv: None,
+        ascii: false,
+    };
+    let mut inputs = Vec::new();
+    let mut options_ended = false;
+    let mut args = argv[2..].iter();
+    while let Some(arg) = args.next() {
+        if options_ended || !arg.starts_with(LONG_PREFIX) {
+            inputs.push(arg.clone());
+            continue;
+        } else if arg == LONG_PREFIX {
+            options_ended = true;
+            continue;
+        }
+        let (name, mut value) = match arg.find(LONG_VALUE_SEP) {
+            Some(index) => (&arg[LONG_PREFIX.len()..index], Some(&arg[index + 1..])),
+            None => (&arg[LONG_PREFIX.len()..], None),
+        };
+        let option = find_long_option(BENCH_OPTIONS, name);
+        if value.is_none() && option.value_required {
+            value = args.next().map(|next| next.as_str());
+        }
+        let values = check_option_value(option, value);
+        match option.name {
+            BENCH_ITERATIONS => settings.iterations = Some(parse_bench_number(BENCH_ITERATIONS, &values[0])),
+            BENCH_TIME => settings.time = Some(parse_bench_number(BENCH_TIME, &values[0])),
+            BENCH_WARMUP => settings.warmup = parse_bench_number(BENCH_WARMUP, &values[0]),
+            BENCH_SAVE => settings.save = Some(values[0].clone()),
+            BENCH_COMPARE => settings.compare = Some(values[0].clone()),
+            BENCH_SWEEP => settings.sweep = Some(parse_bench_number(BENCH_SWEEP, &values[0])),
+            BENCH_SCALE => settings.scale = values[0].clone(),
+            BENCH_STEPS => settings.steps = parse_bench_number(BENCH_STEPS, &values[0]),
+            BENCH_CSV => settings.csv = Some(values[0].clone()),
+            BENCH_ASCII => settings.ascii = true,
+            _ => print_bench_help(&exec_name),
+        }
+    }
+
+    if settings.sweep.is_some() {
+        if !inputs.is_empty() {
+            error_out!("`--sweep` generates its own messages and takes no inputs");
+        } else if settings.save.is_some() || settings.compare.is_some() {
+            error_out!("`--save` and `--compare` do not apply to `--sweep`");
+        } else if settings.sweep == Some(0) {
+            error_out!("`--sweep` must be at least 1 byte");
+        } else if settings.steps < 2 {
+            error_out!("`--steps` must be at least 2");
+        }
+    } else if settings.csv.is_some() || settings.ascii {
+        error_out!("`--csv` and `--ascii` only apply to `--sweep`");
+    } else if inputs.is_empty() {
+        error_out!("You must pass at least one input to benchmark");
+    }
+    if settings.iterations.is_some() && settings.time.is_some() {
+        error_out!("You may pass `--iterations` or `--time`, but not both");
+    } else if settings.iterations.is_some_and(|iterations| iterations < BENCH_MIN_SAMPLES) {
+        error_out!("`--iterations` must be at least 2");
+    } else if settings.time.is_some_and(|time| !time.is_finite() || time <= 0.0) {
+        error_out!("`--time` must be a positive number of seconds");
+    } else if inputs.iter().filter(|arg| assert_stdin(arg)).count() > 1 {
+        error_out!("Standard input can only be hashed once");
+    }
+    (settings, inputs)
+}
+
+fn load_bench_input(arg: &String) -> Vec<u8> {
+    if let Some(bytes) = process_arg(arg) {
+        return bytes;
+    }
+    let mut bytes = Vec::new();
+    let read = if assert_stdin(arg) {
+        std::io::Read::read_to_end(&mut std::io::stdin(), &mut bytes).map(|_| ())
+    } else if assert_gen(arg) {
+        std::io::Read::read_to_end(&mut parse_gen_spec(&arg[GEN_PREFIX_LEN..]), &mut bytes).map(|_| ())
+    } else {
+        is_regular_file(&arg[FILE_PREFIX_LEN..].to_string());
+        std::fs::read(&arg[FILE_PREFIX_LEN..]).map(|content| bytes = content)
+    };
+    if read.is_err() {
+        error_out!("Error occurred reading the input");
+    }
+    bytes
+}
+
+fn time_hashes(message: &Vec<u8>, settings: &BenchSettings) -> Vec<f64> {
+    for _ in 0..settings.warmup {
+        std::hint::black_box(pox_hash(std::hint::black_box(message)));
+    }
+    let budget = std::time::Duration::from_secs_f64(settings.time.unwrap_or(BENCH_DEFAULT_TIME));
+    let mut samples = Vec::new();
+    let started = std::time::Instant::now();
+    loop {
+        let t1 = std::time::Instant::now();
+        let digest = pox_hash(std::hint::black_box(message));
+        let elapsed = t1.elapsed();
+        std::hint::black_box(digest);
+        samples.push(elapsed.as_nanos() as f64);
+
+        let done = match settings.iterations {
+            Some(iterations) => samples.len() >= iterations,
+            None => {
+                (started.elapsed() >= budget && samples.len() >= BENCH_MIN_SAMPLES)
+                    || samples.len() >= BENCH_MAX_SAMPLES
+            }
+        };
+        if done {
+            return samples;
+        }
+    }
+}
+
+fn get_bench_stats(label: String, length: u64, mut samples: Vec<f64>) -> BenchStats {
+    samples.sort_by(|a, b| a.total_cmp(b));
+    let count = samples.len();
+    let mean = samples.iter().sum::<f64>() / count as f64;
+    let variance = samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / (count - 1) as f64;
+    let median = match count % 2 {
+        0 => (samples[count / 2 - 1] + samples[count / 2]) / 2.0,
+        _ => samples[count / 2],
+    };
+    let p99_index = ((count as f64 * BENCH_P99).ceil() as usize).clamp(1, count) - 1;
+    BenchStats {
+        label,
+        length,
+        samples: count,
+        min: samples[0],
+        median,
+        mean,
+        stddev: variance.sqrt(),
+        p99: samples[p99_index],
+    }
+}
+
+fn format_duration(ns: f64) -> String {
+    let (unit, divisor) = BENCH_DURATION_UNITS
+        .iter()
+        .find(|(_, divisor)| ns >= *divisor)
+        .unwrap_or(&BENCH_DURATION_UNITS[BENCH_DURATION_UNITS.len() - 1]);
+    format!("{:.3} {}", ns / divisor, unit)
+}
+
+fn escape_bench_label(label: &String) -> String {
+    label.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn unescape_bench_label(label: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = label.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+fn write_bench_baseline(path: &String, results: &Vec<BenchStats>) {
+    let mut baseline = format!("{}\n", BENCH_BASELINE_HEADER);
+    for stats in results {
+        baseline.push_str(&format!(
+            "{} {} {} {} {} {} {} {}\n",
+            stats.samples,
+            stats.mean,
+            stats.stddev,
+            stats.min,
+            stats.median,
+            stats.p99,
+            stats.length,
+            escape_bench_label(&stats.label)
+        ));
+    }
+    if std::fs::write(path, baseline).is_err() {
+        print!("Could not write `{}`", path);
+        error_out!("Baseline error");
+    }
+}
+
+fn parse_bench_line(line: &str) -> Option<BenchStats> {
+    let parts: Vec<&str> = line.splitn(8, ' ').collect();
+    if parts.len() != 8 {
+        return None;
+    }
+    Some(BenchStats {
+        samples: parts[0].parse().ok()?,
+        mean: parts[1].parse().ok()?,
+        stddev: parts[2].parse().ok()?,
+        min: parts[3].parse().ok()?,
+        median: parts[4].parse().ok()?,
+        p99: parts[5].parse().ok()?,
+        length: parts[6].parse().ok()?,
+        label: unescape_bench_label(parts[7]),
+    })
+}
+
+fn read_bench_baseline(path: &String) -> Vec<BenchStats> {
+    let baseline = match std::fs::read_to_string(path) {
+        Ok(baseline) => baseline,
+        Err(_) => {
+            print!("Could not read `{}`", path);
+            error_out!("Baseline error");
+        }
+    };
+    let mut lines = baseline.lines();
+    if lines.next() != Some(BENCH_BASELINE_HEADER) {
+        print!("`{}` is not a baseline written by `{}{}`", path, LONG_PREFIX, BENCH_SAVE);
+        error_out!("Baseline error");
+    }
+    lines
+        .filter(|line| !line.is_empty())
+        .map(|line| match parse_bench_line(line) {
+            Some(stats) => stats,
+            None => {
+                print!("`{}` has a malformed line: {}", path, line);
+                error_out!("Baseline error");
+            }
+        })
+        .collect()
+}
+
+fn get_t_critical(df: f64) -> f64 {
+    // Cornish-Fisher expansion of the two-sided 95% Student t quantile
+    let z = BENCH_Z_95;
+    z + (z.powi(3) + z) / (4.0 * df) + (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / (96.0 * df.powi(2))
+}
+
+fn compare_bench_stats(current: &BenchStats, baseline: &BenchStats) -> String {
+    let current_var = current.stddev.powi(2) / current.samples as f64;
+    let baseline_var = baseline.stddev.powi(2) / baseline.samples as f64;
+    let standard_error = (current_var + baseline_var).sqrt();
+    let change = (current.mean - baseline.mean) / baseline.mean * 100.0;
+    let direction = if change < 0.0 { "faster" } else { "slower" };
+    if standard_error == 0.0 {
+        return format!("{:.2}% {} (no variance to test)", change.abs(), direction);
+    }
+    let t = (current.mean - baseline.mean) / standard_error;
+    let df = (current_var + baseline_var).powi(2)
+        / (current_var.powi(2) / (current.samples - 1) as f64
+            + baseline_var.powi(2) / (baseline.samples - 1) as f64);
+    match t.abs() > get_t_critical(df) {
+        true => format!("{:.2}% {} (significant, t = {:.2})", change.abs(), direction, t),
+        false => format!("no significant change, {:.2}% {} (t = {:.2})", change.abs(), direction, t),
+    }
+}
+
+fn print_bench_stats(stats: &BenchStats, warmup: usize, baseline: Option<&BenchStats>) {
+    let hashes_per_sec = 1e9 / stats.mean;
+    let mib_per_sec = stats.length as f64 * hashes_per_sec / BYTES_IN_MIB;
+    print!("\x1b[1;32m{}\x1b[0m ({} bytes)\n", stats.label, stats.length);
+    print!("\titerations  {} after {} warmup\n", stats.samples, warmup);
+    print!("\tmin         {}\n", format_duration(stats.min));
+    print!("\tmedian      {}\n", format_duration(stats.median));
+    print!("\tmean        {}\n", format_duration(stats.mean));
+    print!("\tstddev      {}\n", format_duration(stats.stddev));
+    print!("\tp99         {}\n", format_duration(stats.p99));
+    print!("\tthroughput  {:.3} MiB/s, {:.1} hashes/s\n", mib_per_sec, hashes_per_sec);
+    if let Some(baseline) = baseline {
+        print!("\tbaseline    {} mean, {}\n", format_duration(baseline.mean), compare_bench_stats(stats, baseline));
+    }
+}
+
+fn get_sweep_sizes(max: usize, scale: &str, steps: usize) -> Vec<usize> {
+    let mut sizes: Vec<usize> = (0..steps)
+        .map(|step| {
+            let fraction = step as f64 / (steps - 1) as f64;
+            let size = match scale {
+                SCALE_LINEAR => 1.0 + (max - 1) as f64 * fraction,
+                _ => (max as f64).powf(fraction),
+            };
+            size.round() as usize
+        })
+        .collect();
+    for boundary in [SWEEP_BLOCK_BYTES - 1, SWEEP_BLOCK_BYTES, SWEEP_BLOCK_BYTES + 1] {
+        if boundary <= max {
+            sizes.push(boundary);
+        }
+    }
+    sizes.sort();
+    sizes.dedup();
+    sizes
+}
+
+fn get_sweep_message(size: usize) -> Vec<u8> {
+    let mut message = Vec::with_capacity(size);
+    let mut generator = GenReader::new(GenKind::Rand(SplitMix64::new(SWEEP_SEED)), size as u64);
+    std::io::Read::read_to_end(&mut generator, &mut message).unwrap();
+    message
+}
+
+fn get_chart_bar(value: f64, max: f64, ascii: bool) -> String {
+    let eighths = match max > 0.0 {
+        true => (value / max * (CHART_WIDTH * 8) as f64).round() as usize,
+        false => 0,
+    };
+    if ascii {
+        return std::iter::repeat_n(CHART_ASCII, eighths.div_ceil(8)).collect();
+    }
+    let mut bar: String = std::iter::repeat_n(CHART_UNICODE[7], eighths / 8).collect();
+    if eighths % 8 != 0 {
+        bar.push(CHART_UNICODE[eighths % 8 - 1]);
     }
-    read_given_file(&arg[FILE_PREFIX_LEN..].to_string()).as_bytes().to_vec()
+    bar
+}
+
+fn run_sweep(settings: &BenchSettings) {
+    let max = settings.sweep.unwrap();
+    let sizes = get_sweep_sizes(max, &settings.scale, settings.steps);
+    let budget = match settings.iterations {
+        Some(iterations) => format!("{} iterations", iterations),
+        None => format!("{} s", settings.time.unwrap_or(BENCH_DEFAULT_TIME)),
+    };
+    print!(
+        "\x1b[1;32mSize sweep\x1b[0m: {} sizes from 1 to {} bytes on a {} scale, {} per size after {} warmup\n",
+        sizes.len(),
+        max,
+        settings.scale,
+        budget,
+        settings.warmup
+    );
+    print!("{:>10} {:>7} {:>12} {:>12} {:>12} {:>10}\n", "bytes", "blocks", "median", "mean", "ns/byte", "MiB/s");
+
+    let mut rows = Vec::with_capacity(sizes.len());
+    for size in sizes {
+        let message = get_sweep_message(size);
+        let stats = get_bench_stats(size.to_string(), size as u64, time_hashes(&message, settings));
+        let ns_per_byte = stats.mean / size as f64;
+        let mib_per_sec = size as f64 * 1e9 / stats.mean / BYTES_IN_MIB;
+        print!(
+            "{:>10} {:>7} {:>12} {:>12} {:>12.1} {:>10.3}\n",
+            size,
+            size.div_ceil(SWEEP_BLOCK_BYTES),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            ns_per_byte,
+            mib_per_sec
+        );
+        rows.push((stats, ns_per_byte, mib_per_sec));
+    }
+
+    print!("\n\x1b[1;32mns/byte\x1b[0m\n");
+    let max_ns_per_byte = rows.iter().map(|row| row.1).fold(0.0, f64::max);
+    for (stats, ns_per_byte, _) in rows.iter() {
+        print!(
+            "{:>10} |{} {:.1}\n",
+            stats.length,
+            get_chart_bar(*ns_per_byte, max_ns_per_byte, settings.ascii),
+            ns_per_byte
+        );
+    }
+
+    if let Some(ref path) = settings.csv {
+        let mut csv = format!("{}\n", SWEEP_CSV_HEADER);
+        for (stats, ns_per_byte, mib_per_sec) in rows.iter() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                stats.length,
+                (stats.length as usize).div_ceil(SWEEP_BLOCK_BYTES),
+                stats.samples,
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                stats.p99,
+                ns_per_byte,
+                mib_per_sec
+            ));
+        }
+        if std::fs::write(path, csv).is_err() {
+            print!("Could not write `{}`", path);
+            error_out!("Sweep error");
+        }
+    }
+    std::process::exit(0);
+}
+
+fn run_benchmark(argv: &Vec<String>) {
+    let (settings, inputs) = parse_bench_args(argv);
+    if settings.sweep.is_some() {
+        run_sweep(&settings);
+    }
+    let baselines = match settings.compare {
+        Some(ref path) => read_bench_baseline(path),
+        None => Vec::new(),
+    };
+    let mut results = Vec::with_capacity(inputs.len());
+    for arg in inputs.iter() {
+        let message = load_bench_input(arg);
+        let samples = time_hashes(&message, &settings);
+        let stats = get_bench_stats(get_source(arg).1, message.len() as u64, samples);
+        let baseline = baselines.iter().find(|baseline| baseline.label == stats.label);
+        if settings.compare.is_some() && baseline.is_none() {
+            print!("\x1b[1;33mNo baseline for `{}`\x1b[0m\n", stats.label);
+        }
+        print_bench_stats(&stats, settings.warmup, baseline);
+        results.push(stats);
+    }
+    if let Some(ref path) = settings.save {
+        write_bench_baseline(path, &results);
+    }
+    std::process::exit(0);
 }
 
 #[allow(unused_assignments)]
 fn main() {
-    let argv: Vec<String> = std::env::args().collect();
-    validate_flags(&argv);
+    let mut argv: Vec<String> = std::env::args().collect();
+    if argv.get(1).is_some_and(|arg| arg == SUBCOMMAND_BENCH) {
+        run_benchmark(&argv);
+    }
+    let mut expected = None;
+    let legacy = assert_legacy_flags(&argv);
+    if !legacy {
+        let translated = translate_long_options(&argv);
+        argv = translated.0;
+        expected = translated.1;
+    }
+    validate_flags(&argv, legacy);
     let flags_arg = argv.get(1).unwrap();
+    let output_mode = get_output_mode(flags_arg);
 
-    if !arg_has_flag(flags_arg, FLAG_NHEADER) {
+    if !arg_has_flag(flags_arg, FLAG_NHEADER) && output_mode == '\0' {
         print!(
             "\x1b[1;30;47m   PoxHashRunner   |  Rust  |  March 2023 - Chubak Bidpaa  |  MIT  \x1b[0m\n"
         );
     }
     let echo_arg = arg_has_flag(flags_arg, FLAG_ECHO);
-    let mut hashes = vec![PoxDigest::default(); argv.len() - 2];
+    let mut records: Vec<Record> = Vec::with_capacity(argv.len() - 2);
+    let mut total_time = 0u128;
     match arg_has_flag(&flags_arg, FLAG_JOIN) {
         true => {
             let args_joined = join_args(&argv[2..].to_vec());
@@ -719,23 +2810,53 @@ fn main() {
                 print!("Joined Args: \n`{}`\n", args_joined);
             }
             let t1 = get_time_in_ns();
-            hashes[0] = pox_hash(&args_joined.as_bytes().to_vec());
+            let digest = pox_hash(&args_joined.as_bytes().to_vec());
             let t2 = get_time_in_ns();
-            print_hashes(&hashes[..1].to_vec(), &flags_arg, t2 - t1);
+            total_time = t2 - t1;
+            records.push(Record {
+                source: SOURCE_JOINED,
+                length: args_joined.len() as u64,
+                label: args_joined,
+                digest,
+                time: total_time,
+            });
         }
         false => {
-            let (mut t1, mut t2, mut total_time) = (0u128, 0u128, 0u128);
+            let (mut t1, mut t2) = (0u128, 0u128);
+            if argv[2..].iter().filter(|arg| assert_stdin(arg)).count() > 1 {
+                error_out!("Standard input can only be hashed once");
+            }
             for (i, arg) in argv[2..].into_iter().enumerate() {
                 if echo_arg {
                     print!("Arg {}: {}\n", i + 1, arg);
                 }
                 let processed_arg = process_arg(arg);
                 t1 = get_time_in_ns();
-                hashes[i] = pox_hash(&processed_arg);
+                let (digest, length) = match processed_arg {
+                    Some(bytes) => (pox_hash(&bytes), bytes.len() as u64),
+                    None => hash_stream(arg),
+                };
                 t2 = get_time_in_ns();
                 total_time += t2 - t1;
+                let (source, label) = get_source(arg);
+                records.push(Record {
+                    source,
+                    label,
+                    length,
+                    digest,
+                    time: t2 - t1,
+                });
             }
-            print_hashes(&hashes, &flags_arg, total_time);
         }
     }
+
+    let hashes = records.iter().map(|record| record.digest.clone()).collect::<Vec<PoxDigest>>();
+    if output_mode != '\0' {
+        print_records(&records, &flags_arg);
+    } else {
+        print_hashes(&hashes, &flags_arg, total_time);
+    }
+    if let Some(expected) = expected {
+        std::process::exit(verify_expected(&expected, &hashes[0], output_mode != '\0'));
+    }
 }
@@ -0,0 +1,51 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//           SplitMix64 generator for the runner, poxh-vectors and tests           //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+// SplitMix64, chosen because it is a handful of lines in every port that may want to
+// regenerate the corpus instead of reading the committed file. Callers that need other
+// shapes of random input add their own `impl SplitMix64` next to where they use it.
+pub struct SplitMix64 {
+    pub state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next_u64() as u8).collect()
+    }
+}
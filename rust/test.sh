@@ -0,0 +1,32 @@
+#!/bin/bash
+
+PROJ_DIR="/tmp/rustpoxh/tests"
+COMPILE_NAME_RLIB="libpoxh.rlib"
+CRATE_NAME="libpoxh"
+DERIVE_CRATE_NAME="poxh_derive"
+DERIVE_NAME="poxh_derive.rs"
+COMPILE_NAME_DERIVE="libpoxh_derive.so"
+BASE_DIR="rust"
+LIBPOX_NAME="libpoxh.rs"
+TESTS_DIR="tests"
+C_DIR="c"
+C_SHIM_NAME="support/libpoxh_c.c"
+C_SHIM_LIB="poxh_c"
+
+rm -rf $PROJ_DIR
+mkdir -p $PROJ_DIR
+rustc $BASE_DIR/$LIBPOX_NAME -O --crate-type lib --crate-name $CRATE_NAME -o $PROJ_DIR/$COMPILE_NAME_RLIB || exit 1
+rustc $BASE_DIR/$DERIVE_NAME -O --crate-type proc-macro --crate-name $DERIVE_CRATE_NAME -o $PROJ_DIR/$COMPILE_NAME_DERIVE || exit 1
+cc -O2 -I $C_DIR -c $BASE_DIR/$TESTS_DIR/$C_SHIM_NAME -o $PROJ_DIR/$C_SHIM_LIB.o || exit 1
+ar rcs $PROJ_DIR/lib$C_SHIM_LIB.a $PROJ_DIR/$C_SHIM_LIB.o || exit 1
+
+failed="0"
+for test_file in $BASE_DIR/$TESTS_DIR/*.rs; do
+    test_name=$(basename $test_file .rs)
+    rustc --test $test_file -O --extern $CRATE_NAME=$PROJ_DIR/$COMPILE_NAME_RLIB --extern $DERIVE_CRATE_NAME=$PROJ_DIR/$COMPILE_NAME_DERIVE -L $PROJ_DIR -o $PROJ_DIR/$test_name || exit 1
+    $PROJ_DIR/$test_name $@ || failed="1"
+done
+
+if [ "$failed" = "1" ]; then
+    exit 1;
+fi
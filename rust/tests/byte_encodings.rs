@@ -0,0 +1,271 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                        ByteEncoding tests for libpoxh.rs                        //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, ByteEncoding, ByteEncodingError};
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+const ALL_ENCODINGS: [ByteEncoding; 7] = [
+    ByteEncoding::Base32,
+    ByteEncoding::Base32NoPad,
+    ByteEncoding::Base64Url,
+    ByteEncoding::Base64UrlNoPad,
+    ByteEncoding::Base58,
+    ByteEncoding::Proquint,
+    ByteEncoding::Mnemonic,
+];
+
+// Expected strings were produced with Python's `base64` module and independent
+// base58 and proquint transcriptions, not with this library
+const KNOWN_BYTES: &'static [(
+    [u8; 8],
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+)] = &[
+    (
+        [208, 7, 140, 75, 228, 210, 243, 123],
+        "2ADYYS7E2LZXW===",
+        "0AeMS-TS83s=",
+        "bo9W85dL3FG",
+        "tabal-mudar-vigif-zatur",
+    ),
+    (
+        [0, 0, 0, 0, 0, 0, 0, 0],
+        "AAAAAAAAAAAAA===",
+        "AAAAAAAAAAA=",
+        "11111111",
+        "babab-babab-babab-babab",
+    ),
+    (
+        [255, 255, 255, 255, 255, 255, 255, 255],
+        "7777777777776===",
+        "__________8=",
+        "jpXCZedGfVQ",
+        "zuzuz-zuzuz-zuzuz-zuzuz",
+    ),
+    (
+        [0, 0, 1, 2, 3, 4, 5, 6],
+        "AAAACAQDAQCQM===",
+        "AAABAgMEBQY=",
+        "11W7LcTy7",
+        "babab-bahaf-basah-bihak",
+    ),
+    (
+        [127, 0, 0, 1, 0, 0, 0, 0],
+        "P4AAAAIAAAAAA===",
+        "fwAAAQAAAAA=",
+        "NF4RmdYB47y",
+        "lusab-babad-babab-babab",
+    ),
+];
+
+#[test]
+fn known_bytes_encode_as_expected() {
+    for (bytes, base32, base64url, base58, proquint) in KNOWN_BYTES {
+        assert_eq!(ByteEncoding::Base32.encode_bytes(bytes), *base32);
+        assert_eq!(
+            ByteEncoding::Base32NoPad.encode_bytes(bytes),
+            base32.trim_end_matches('=')
+        );
+        assert_eq!(ByteEncoding::Base64Url.encode_bytes(bytes), *base64url);
+        assert_eq!(
+            ByteEncoding::Base64UrlNoPad.encode_bytes(bytes),
+            base64url.trim_end_matches('=')
+        );
+        assert_eq!(ByteEncoding::Base58.encode_bytes(bytes), *base58);
+        assert_eq!(ByteEncoding::Proquint.encode_bytes(bytes), *proquint);
+    }
+}
+
+#[test]
+fn mnemonic_spells_one_word_per_byte() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    let mnemonic = ByteEncoding::Mnemonic.encode(&digest);
+    assert_eq!(mnemonic.split(' ').count(), 8);
+    assert_eq!(
+        ByteEncoding::Mnemonic.encode_bytes(&[0, 1, 2, 3, 252, 253, 254, 255]),
+        "acid acorn actor adobe zero zigzag zinc zipper"
+    );
+}
+
+#[test]
+fn every_byte_value_round_trips() {
+    for encoding in ALL_ENCODINGS.iter() {
+        for b in 0..=255u8 {
+            let bytes = [b, 0, b, 0xff, b, b.wrapping_add(1), 0, b];
+            let encoded = encoding.encode_bytes(&bytes);
+            assert_eq!(
+                encoding.decode_bytes(&encoded),
+                Ok(bytes),
+                "{:?} {}",
+                encoding,
+                encoded
+            );
+        }
+    }
+}
+
+#[test]
+fn random_digests_round_trip() {
+    let mut rng = SplitMix64 { state: 0x42797465 };
+    for _ in 0..10_000 {
+        let value = rng.next_u64();
+        // Shift in zero bytes now and then to exercise base58's leading `1`s
+        let bytes = (value >> (8 * (rng.next_u64() % 8))).to_be_bytes();
+        for encoding in ALL_ENCODINGS.iter() {
+            let encoded = encoding.encode_bytes(&bytes);
+            assert_eq!(
+                encoding.decode_bytes(&encoded),
+                Ok(bytes),
+                "{:?} {}",
+                encoding,
+                encoded
+            );
+        }
+    }
+}
+
+#[test]
+fn digests_round_trip_with_every_view() {
+    for message in ["PoxHash", "PoHxash", "Masud"].iter() {
+        let digest = pox_hash(&message.as_bytes().to_vec());
+        for encoding in ALL_ENCODINGS.iter() {
+            let decoded = encoding.decode(&encoding.encode(&digest)).unwrap();
+            assert_eq!(decoded.bytes, digest.bytes);
+            assert_eq!(decoded.words, digest.words);
+            assert_eq!(decoded.hexdigest, digest.hexdigest);
+        }
+    }
+}
+
+#[test]
+fn mnemonic_decoding_ignores_case_and_spacing() {
+    assert_eq!(
+        ByteEncoding::Mnemonic.decode_bytes("  Acid ACORN actor\tadobe zero zigzag zinc  zipper\n"),
+        Ok([0, 1, 2, 3, 252, 253, 254, 255])
+    );
+}
+
+#[test]
+fn malformed_input_is_rejected() {
+    assert_eq!(
+        ByteEncoding::Base32.decode_bytes("2ADYYS7E2LZXW"),
+        Err(ByteEncodingError::Length {
+            expected: 16,
+            found: 13
+        })
+    );
+    assert_eq!(
+        ByteEncoding::Base32.decode_bytes("2ADYYS7E2LZXW=A="),
+        Err(ByteEncodingError::InvalidChar {
+            position: 14,
+            found: 'A'
+        })
+    );
+    assert_eq!(
+        ByteEncoding::Base32NoPad.decode_bytes("2ADYYS1E2LZXW"),
+        Err(ByteEncodingError::InvalidChar {
+            position: 6,
+            found: '1'
+        })
+    );
+    // The last base32 digit carries a single bit; `X` would set the unused four
+    assert_eq!(
+        ByteEncoding::Base32NoPad.decode_bytes("2ADYYS7E2LZXX"),
+        Err(ByteEncodingError::TrailingBits)
+    );
+    assert_eq!(
+        ByteEncoding::Base64UrlNoPad.decode_bytes("0AeMS+TS83s"),
+        Err(ByteEncodingError::InvalidChar {
+            position: 5,
+            found: '+'
+        })
+    );
+    assert_eq!(
+        ByteEncoding::Base58.decode_bytes("bo0W85dL3FG"),
+        Err(ByteEncodingError::InvalidChar {
+            position: 2,
+            found: '0'
+        })
+    );
+    assert_eq!(
+        ByteEncoding::Base58.decode_bytes("1bo9W85dL3FG"),
+        Err(ByteEncodingError::ByteCount(9))
+    );
+    assert_eq!(
+        ByteEncoding::Base58.decode_bytes("zzzzzzzzzzzz"),
+        Err(ByteEncodingError::Overflow)
+    );
+    assert_eq!(
+        ByteEncoding::Base58.decode_bytes("W7LcTy7"),
+        Err(ByteEncodingError::ByteCount(6))
+    );
+    assert_eq!(
+        ByteEncoding::Proquint.decode_bytes("tabal-mudar_vigif-zatur"),
+        Err(ByteEncodingError::InvalidChar {
+            position: 11,
+            found: '_'
+        })
+    );
+    assert_eq!(
+        ByteEncoding::Proquint.decode_bytes("tabal-mudar-vbgif-zatur"),
+        Err(ByteEncodingError::InvalidChar {
+            position: 13,
+            found: 'b'
+        })
+    );
+    assert_eq!(
+        ByteEncoding::Mnemonic.decode_bytes("acid acorn actor adobe zero zigzag zinc"),
+        Err(ByteEncodingError::WordCount(7))
+    );
+    assert_eq!(
+        ByteEncoding::Mnemonic.decode_bytes("acid acorn actor adobe zero zigzag zinc zippers"),
+        Err(ByteEncodingError::UnknownWord {
+            position: 7,
+            found: "zippers".to_string()
+        })
+    );
+}
+
+#[test]
+fn mnemonic_words_differ_in_their_first_four_letters() {
+    let mut prefixes = (0..=255u8)
+        .map(|b| {
+            let word = ByteEncoding::Mnemonic.encode_bytes(&[b; 8]);
+            word.split(' ').next().unwrap().chars().take(4).collect::<String>()
+        })
+        .collect::<Vec<String>>();
+    prefixes.sort();
+    prefixes.dedup();
+    assert_eq!(prefixes.len(), 256);
+}
@@ -0,0 +1,141 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                 Byte order and wire format tests for libpoxh.rs                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, PoxDigest, WireError};
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+const SAMPLE_MESSAGES: &'static [&'static str] = &["PoxHash", "PoHxash", "Masud", ""];
+
+fn assert_same_digest(a: &PoxDigest, b: &PoxDigest) {
+    assert_eq!(a.words, b.words);
+    assert_eq!(a.bytes, b.bytes);
+    assert_eq!(a.doubles, b.doubles);
+    assert_eq!(a.quad, b.quad);
+    assert_eq!(a.hexdigest, b.hexdigest);
+    assert_eq!(a.bindigest, b.bindigest);
+}
+
+#[test]
+fn little_endian_views_are_the_canonical_fields() {
+    for message in SAMPLE_MESSAGES {
+        let digest = pox_hash(&message.as_bytes().to_vec());
+        assert_eq!(digest.to_le_bytes(), digest.bytes);
+        assert_eq!(digest.to_le_doubles(), digest.doubles);
+        assert_eq!(digest.to_le_quad(), digest.quad);
+    }
+}
+
+#[test]
+fn big_endian_views_read_like_the_hexdigest() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    assert_eq!(
+        digest.to_be_bytes(),
+        [0x07, 0xd0, 0x4b, 0x8c, 0xd2, 0xe4, 0x7b, 0xf3]
+    );
+    assert_eq!(digest.to_be_doubles(), [0x07d04b8c, 0xd2e47bf3]);
+    assert_eq!(digest.to_be_quad(), 0x07d04b8cd2e47bf3);
+    assert_eq!(digest.to_le_quad(), 0x7bf3d2e44b8c07d0);
+
+    for message in SAMPLE_MESSAGES {
+        let digest = pox_hash(&message.as_bytes().to_vec());
+        assert_eq!(
+            digest.to_be_quad(),
+            u64::from_str_radix(&digest.hexdigest, 16).unwrap()
+        );
+    }
+}
+
+#[test]
+fn byte_orders_differ_only_within_each_word() {
+    let mut rng = SplitMix64 {
+        state: 0x456e6469616e,
+    };
+    for _ in 0..10_000 {
+        let digest = PoxDigest::from_le_quad(rng.next_u64());
+        let le = digest.to_le_bytes();
+        let be = digest.to_be_bytes();
+        for i in 0..4 {
+            assert_eq!([le[i * 2], le[i * 2 + 1]], [be[i * 2 + 1], be[i * 2]]);
+        }
+    }
+}
+
+#[test]
+fn every_view_round_trips() {
+    let mut rng = SplitMix64 {
+        state: 0x526f756e64,
+    };
+    for _ in 0..10_000 {
+        let digest = PoxDigest::from_le_quad(rng.next_u64());
+        assert_same_digest(&PoxDigest::from_le_bytes(digest.to_le_bytes()), &digest);
+        assert_same_digest(&PoxDigest::from_be_bytes(digest.to_be_bytes()), &digest);
+        assert_same_digest(&PoxDigest::from_le_doubles(digest.to_le_doubles()), &digest);
+        assert_same_digest(&PoxDigest::from_be_doubles(digest.to_be_doubles()), &digest);
+        assert_same_digest(&PoxDigest::from_le_quad(digest.to_le_quad()), &digest);
+        assert_same_digest(&PoxDigest::from_be_quad(digest.to_be_quad()), &digest);
+        assert_same_digest(&PoxDigest::from_wire(&digest.to_wire()).unwrap(), &digest);
+    }
+}
+
+#[test]
+fn wire_format_is_a_version_byte_and_the_le_bytes() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    assert_eq!(
+        digest.to_wire(),
+        [0x01, 0xd0, 0x07, 0x8c, 0x4b, 0xe4, 0xd2, 0xf3, 0x7b]
+    );
+}
+
+#[test]
+fn wire_format_rejects_other_sizes_and_versions() {
+    let wire = pox_hash(&"PoxHash".as_bytes().to_vec()).to_wire();
+    assert_eq!(
+        PoxDigest::from_wire(&wire[..8]).err(),
+        Some(WireError::Length(8))
+    );
+    let mut longer = wire.to_vec();
+    longer.push(0);
+    assert_eq!(
+        PoxDigest::from_wire(&longer).err(),
+        Some(WireError::Length(10))
+    );
+    let mut future = wire;
+    future[0] = 2;
+    assert_eq!(
+        PoxDigest::from_wire(&future).err(),
+        Some(WireError::UnknownVersion(2))
+    );
+    assert_eq!(
+        WireError::UnknownVersion(2).to_string(),
+        "unknown wire digest version 2"
+    );
+}
@@ -0,0 +1,297 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//            Differential tests of libpoxh.rs against c/libpoxh.h                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+// `rust/test.sh` compiles `tests/support/libpoxh_c.c` against `c/libpoxh.h` into
+// `libpoxh_c.a` before this file is built. The C `pox_hash` measures its input with
+// `strlen`, so every message generated here is non-empty and free of NUL bytes.
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, PoxDigest};
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+#[repr(C)]
+struct CPoxDigest {
+    sexdigest: [u8; 13],
+    vigdigest: [u8; 17],
+    hexdigest: [u8; 17],
+    tetdigest: [u8; 21],
+    duodigest: [u8; 21],
+    octdigest: [u8; 25],
+    sendigest: [u8; 29],
+    bindigest: [u8; 65],
+    bytes: [u8; 8],
+    words: [u16; 4],
+    doubles: [u32; 2],
+    quad: u64,
+}
+
+#[link(name = "poxh_c", kind = "static")]
+extern "C" {
+    fn poxh_c_hash(message: *const u8) -> CPoxDigest;
+}
+
+// Every 64-byte block costs milliseconds in both ports, so the default run covers the
+// block boundaries and a couple thousand random messages. The `soak_*` tests push the
+// total past 300,000 messages and are run with `bash rust/test.sh --ignored`.
+const QUICK_RANDOM_NUM: usize = 2_000;
+const SOAK_RANDOM_SHORT_NUM: usize = 200_000;
+const SOAK_RANDOM_LONG_NUM: usize = 1_000;
+const RANDOM_SHORT_MAX_LEN: u64 = 64;
+const RANDOM_LONG_MAX_LEN: u64 = 4096;
+const REPEAT_MAX_LEN: usize = 300;
+const REPEAT_BYTES: &'static [u8] = &[0x01, 0x20, 0x41, 0x7f, 0x80, 0xaa, 0xfe, 0xff];
+const BOUNDARY_LENGTHS: &'static [usize] = &[1, 2, 3, 62, 63, 64, 65, 66, 126, 127, 128, 129, 130];
+const SEED_QUICK: u64 = 0x436f6d70617265;
+const SEED_SHORT: u64 = 0x53686f7274;
+const SEED_LONG: u64 = 0x4c6f6e67;
+
+impl SplitMix64 {
+    fn message_at(seed: u64, index: usize, max_len: u64) -> Vec<u8> {
+        SplitMix64::new(seed ^ (index as u64).wrapping_mul(0x2545f4914f6cdd1d)).non_nul_message(max_len)
+    }
+
+    fn non_nul_message(&mut self, max_len: u64) -> Vec<u8> {
+        let len = 1 + self.next_u64() % max_len;
+        (0..len).map(|_| 1 + (self.next_u64() % 255) as u8).collect()
+    }
+}
+
+fn c_string(field: &[u8]) -> String {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+fn c_pox_hash(message: &[u8]) -> CPoxDigest {
+    let mut terminated = message.to_vec();
+    terminated.push(0);
+    unsafe { poxh_c_hash(terminated.as_ptr()) }
+}
+
+fn describe_mismatch(rust: &PoxDigest, c: &CPoxDigest) -> Option<String> {
+    let fields: [(&str, &str, String); 8] = [
+        ("sexdigest", &rust.sexdigest, c_string(&c.sexdigest)),
+        ("vigdigest", &rust.vigdigest, c_string(&c.vigdigest)),
+        ("hexdigest", &rust.hexdigest, c_string(&c.hexdigest)),
+        ("tetdigest", &rust.tetdigest, c_string(&c.tetdigest)),
+        ("duodigest", &rust.duodigest, c_string(&c.duodigest)),
+        ("octdigest", &rust.octdigest, c_string(&c.octdigest)),
+        ("sendigest", &rust.sendigest, c_string(&c.sendigest)),
+        ("bindigest", &rust.bindigest, c_string(&c.bindigest)),
+    ];
+    let mut mismatches = Vec::new();
+    if rust.words != c.words {
+        mismatches.push(format!("words: rust {:?}, c {:?}", rust.words, c.words));
+    }
+    if rust.bytes != c.bytes {
+        mismatches.push(format!("bytes: rust {:?}, c {:?}", rust.bytes, c.bytes));
+    }
+    if rust.doubles != c.doubles {
+        mismatches.push(format!("doubles: rust {:?}, c {:?}", rust.doubles, c.doubles));
+    }
+    if rust.quad != c.quad {
+        mismatches.push(format!("quad: rust {}, c {}", rust.quad, c.quad));
+    }
+    for (name, ours, theirs) in fields.iter() {
+        if ours != theirs {
+            mismatches.push(format!("{}: rust {}, c {}", name, ours, theirs));
+        }
+    }
+
+    if mismatches.is_empty() {
+        None
+    } else {
+        Some(mismatches.join("\n\t"))
+    }
+}
+
+fn disagrees(message: &[u8]) -> bool {
+    describe_mismatch(&pox_hash(&message.to_vec()), &c_pox_hash(message)).is_some()
+}
+
+// Delta-debugging style shrinker: drop ever smaller runs of bytes, then lower the
+// remaining byte values, keeping each step only if the message still `fails`
+fn shrink<F: Fn(&[u8]) -> bool>(message: &[u8], fails: F) -> Vec<u8> {
+    let mut current = message.to_vec();
+    let mut chunk = (current.len() / 2).max(1);
+    while chunk > 0 {
+        let mut start = 0;
+        while start < current.len() && current.len() > 1 {
+            let end = (start + chunk).min(current.len());
+            let mut candidate = current[..start].to_vec();
+            candidate.extend_from_slice(&current[end..]);
+            if !candidate.is_empty() && fails(&candidate) {
+                current = candidate;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+
+    for i in 0..current.len() {
+        for lower in [1u8, 0x20, 0x41, current[i] / 2].iter() {
+            if *lower == 0 || *lower >= current[i] {
+                continue;
+            }
+            let mut candidate = current.clone();
+            candidate[i] = *lower;
+            if fails(&candidate) {
+                current = candidate;
+            }
+        }
+    }
+    current
+}
+
+fn reproducer(message: &[u8]) -> String {
+    let minimal = shrink(message, disagrees);
+    let digest = pox_hash(&minimal);
+    format!(
+        "minimal reproducer ({} of {} bytes): int={}\n\t{}",
+        minimal.len(),
+        message.len(),
+        minimal
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<String>>()
+            .join(","),
+        describe_mismatch(&digest, &c_pox_hash(&minimal)).unwrap_or_default()
+    )
+}
+
+fn assert_agree<I: Iterator<Item = Vec<u8>>>(messages: I) -> usize {
+    let mut count = 0;
+    for message in messages {
+        assert!(!message.is_empty() && !message.contains(&0));
+        if disagrees(&message) {
+            panic!("libpoxh.rs and libpoxh.h disagree; {}", reproducer(&message));
+        }
+        count += 1;
+    }
+    count
+}
+
+// Splits `0..count` over the available cores; `message` must be a pure function of the index
+fn assert_agree_parallel<F>(count: usize, message: F) -> usize
+where
+    F: Fn(usize) -> Vec<u8> + Send + Sync + 'static,
+{
+    let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let message = std::sync::Arc::new(message);
+    let handles = (0..workers)
+        .map(|worker| {
+            let message = message.clone();
+            std::thread::spawn(move || {
+                assert_agree((worker..count).step_by(workers).map(|i| message(i)))
+            })
+        })
+        .collect::<Vec<_>>();
+    handles.into_iter().map(|h| h.join().unwrap()).sum()
+}
+
+#[test]
+fn spec_samples_agree() {
+    let samples = ["PoxHash", "oPxHash", "PoxaHsh", "PxoHash", "Masud", "Masvd"];
+    assert_agree(samples.iter().map(|s| s.as_bytes().to_vec()));
+}
+
+#[test]
+fn all_single_bytes_agree() {
+    assert_eq!(assert_agree((1..=255u8).map(|b| vec![b])), 255);
+}
+
+#[test]
+fn block_boundaries_agree() {
+    let repeated = REPEAT_BYTES
+        .iter()
+        .flat_map(|b| BOUNDARY_LENGTHS.iter().map(move |len| vec![*b; *len]));
+    let counting = BOUNDARY_LENGTHS
+        .iter()
+        .map(|len| (0..*len).map(|i| 1 + (i % 255) as u8).collect());
+    assert_agree(repeated.chain(counting));
+}
+
+#[test]
+fn random_messages_agree() {
+    let count = assert_agree_parallel(QUICK_RANDOM_NUM, |i| {
+        SplitMix64::message_at(SEED_QUICK, i, RANDOM_SHORT_MAX_LEN)
+    });
+    assert_eq!(count, QUICK_RANDOM_NUM);
+}
+
+#[test]
+#[ignore]
+fn soak_all_double_bytes_agree() {
+    let count = assert_agree_parallel(255 * 255, |i| vec![1 + (i / 255) as u8, 1 + (i % 255) as u8]);
+    assert_eq!(count, 255 * 255);
+}
+
+#[test]
+#[ignore]
+fn soak_repeated_and_counting_agree() {
+    let repeats = REPEAT_BYTES.len() * REPEAT_MAX_LEN;
+    let count = assert_agree_parallel(repeats + REPEAT_MAX_LEN, move |i| {
+        if i < repeats {
+            vec![REPEAT_BYTES[i / REPEAT_MAX_LEN]; 1 + i % REPEAT_MAX_LEN]
+        } else {
+            (0..=i - repeats).map(|j| 1 + (j % 255) as u8).collect()
+        }
+    });
+    assert_eq!(count, repeats + REPEAT_MAX_LEN);
+}
+
+#[test]
+#[ignore]
+fn soak_random_short_messages_agree() {
+    let count = assert_agree_parallel(SOAK_RANDOM_SHORT_NUM, |i| {
+        SplitMix64::message_at(SEED_SHORT, i, RANDOM_SHORT_MAX_LEN)
+    });
+    assert_eq!(count, SOAK_RANDOM_SHORT_NUM);
+}
+
+#[test]
+#[ignore]
+fn soak_random_long_messages_agree() {
+    let count = assert_agree_parallel(SOAK_RANDOM_LONG_NUM, |i| {
+        SplitMix64::message_at(SEED_LONG, i, RANDOM_LONG_MAX_LEN)
+    });
+    assert_eq!(count, SOAK_RANDOM_LONG_NUM);
+}
+
+#[test]
+fn shrinker_keeps_a_failing_property() {
+    // The shrinker only runs for real when the ports drift apart, so exercise it
+    // against a stand-in property instead
+    let has_high_byte = |message: &[u8]| message.iter().any(|b| *b > 0xf0);
+    assert_eq!(shrink(b"PoxHash \xfa PoxHash", has_high_byte), vec![0xfa]);
+    let has_pair = |message: &[u8]| message.windows(2).any(|w| w[0] > w[1]);
+    assert_eq!(shrink(b"abcdzefgh", has_pair), b"z\x01".to_vec());
+}
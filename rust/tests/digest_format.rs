@@ -0,0 +1,178 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                     Digest formatting tests for libpoxh.rs                      //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, DigestFormat, PoxDigest, RadixEncoding};
+
+fn sample() -> PoxDigest {
+    pox_hash(&"PoxHash".as_bytes().to_vec())
+}
+
+#[test]
+fn hex_without_flags_is_the_bare_digest() {
+    let digest = sample();
+    assert_eq!(format!("{:X}", digest), "07D04B8CD2E47BF3");
+    assert_eq!(format!("{:x}", digest), "07d04b8cd2e47bf3");
+}
+
+#[test]
+fn alternate_adds_the_integer_prefixes() {
+    let digest = sample();
+    assert_eq!(format!("{:#x}", digest), "0x07d04b8cd2e47bf3");
+    assert_eq!(format!("{:#X}", digest), "0x07D04B8CD2E47BF3");
+    assert_eq!(format!("{:#o}", digest), "0o003720045614151344075763");
+    assert_eq!(
+        format!("{:#b}", digest),
+        "0b0000011111010000010010111000110011010010111001000111101111110011"
+    );
+}
+
+#[test]
+fn width_fill_and_alignment_are_honoured() {
+    let digest = sample();
+    // Right-aligned by default, like integers
+    assert_eq!(format!("{:20x}", digest), "    07d04b8cd2e47bf3");
+    assert_eq!(format!("{:<20x}", digest), "07d04b8cd2e47bf3    ");
+    assert_eq!(format!("{:>20x}", digest), "    07d04b8cd2e47bf3");
+    assert_eq!(format!("{:*^20X}", digest), "**07D04B8CD2E47BF3**");
+    assert_eq!(format!("{:020x}", digest), "000007d04b8cd2e47bf3");
+    assert_eq!(format!("{:#020x}", digest), "0x0007d04b8cd2e47bf3");
+    assert_eq!(
+        format!("{:>width$X}", digest, width = 4),
+        "07D04B8CD2E47BF3"
+    );
+}
+
+#[test]
+fn digests_have_no_sign() {
+    let digest = sample();
+    assert_eq!(format!("{:+x}", digest), "07d04b8cd2e47bf3");
+    assert_eq!(format!("{:+#X}", digest), "0x07D04B8CD2E47BF3");
+    assert_eq!(format!("{:+020x}", digest), "000007d04b8cd2e47bf3");
+    assert_eq!(format!("{:+.4o}", digest), "0037");
+}
+
+#[test]
+fn precision_truncates_to_the_leading_digits() {
+    let digest = sample();
+    assert_eq!(format!("{:.8x}", digest), "07d04b8c");
+    assert_eq!(format!("{:#.4X}", digest), "0x07D0");
+    assert_eq!(format!("{:>10.4o}", digest), "      0037");
+    assert_eq!(format!("{:.0b}", digest), "");
+    assert_eq!(format!("{:.64x}", digest), "07d04b8cd2e47bf3");
+}
+
+#[test]
+fn display_lists_each_view_once() {
+    let display = format!("{}", sample());
+    let labels = display
+        .lines()
+        .map(|line| line.split(':').next().unwrap())
+        .collect::<Vec<&str>>();
+    assert_eq!(
+        labels,
+        [
+            "Sexdigest",
+            "Vigdigest",
+            "Hexdigest",
+            "Tetdigest",
+            "Duodigest",
+            "Octdigest",
+            "Sendigest",
+            "Bindigest",
+            "Bytes",
+            "Words",
+            "Doubles",
+            "Quad"
+        ]
+    );
+    assert!(display.contains("Hexdigest: 07D04B8CD2E47BF3\n"));
+}
+
+#[test]
+fn digest_format_defaults_to_plain_hex() {
+    let digest = sample();
+    assert_eq!(DigestFormat::new().format(&digest), digest.hexdigest);
+    assert_eq!(DigestFormat::default(), DigestFormat::new());
+}
+
+#[test]
+fn digest_format_groups_from_the_left() {
+    let digest = sample();
+    assert_eq!(
+        DigestFormat::new().group(4, "-").format(&digest),
+        "07D0-4B8C-D2E4-7BF3"
+    );
+    assert_eq!(
+        DigestFormat::new().group(5, " ").format(&digest),
+        "07D04 B8CD2 E47BF 3"
+    );
+    assert_eq!(
+        DigestFormat::new().group(16, "-").format(&digest),
+        "07D04B8CD2E47BF3"
+    );
+    assert_eq!(
+        DigestFormat::new().group(0, "-").format(&digest),
+        "07D04B8CD2E47BF3"
+    );
+}
+
+#[test]
+fn digest_format_combines_case_prefix_and_encoding() {
+    let digest = sample();
+    assert_eq!(
+        DigestFormat::new()
+            .lowercase()
+            .group(4, ":")
+            .prefix("pox:")
+            .format(&digest),
+        "pox:07d0:4b8c:d2e4:7bf3"
+    );
+    assert_eq!(
+        DigestFormat::new()
+            .encoding(RadixEncoding::TET)
+            .lowercase()
+            .group(5, "_")
+            .format(&digest),
+        "00t2w_07096_15964_0e7w7"
+    );
+    assert_eq!(
+        DigestFormat::new()
+            .encoding(RadixEncoding::BASE36)
+            .uppercase()
+            .prefix("#")
+            .format(&digest),
+        format!("#{}", RadixEncoding::BASE36.encode(&digest).to_uppercase())
+    );
+    assert_eq!(
+        DigestFormat::new()
+            .encoding(RadixEncoding::SEX)
+            .group(3, ".")
+            .format_words(&digest.words),
+        "0XK.5MK.Exm.8mp"
+    );
+}
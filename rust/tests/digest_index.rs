@@ -0,0 +1,279 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                               Digest Index Tests                                //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{
+    pox_hash, ByteEncoding, DigestEncoding, DigestIndex, Multihash, PoxDigest, PrefixError,
+    RadixEncoding, TruncateError, TruncatedDigest,
+};
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+// Hexdigests 12340000..., 12350000..., 12ab0000... and fff00000...
+fn small_index() -> DigestIndex {
+    [
+        [0x1234, 0, 0, 0],
+        [0x1235, 0, 0, 0],
+        [0x12ab, 0, 0, 0],
+        [0xfff0, 0, 0, 0],
+    ]
+    .iter()
+    .map(|words| PoxDigest::from_words(*words))
+    .collect()
+}
+
+#[test]
+fn truncations_are_leading_little_endian_bytes() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    assert_eq!(digest.truncate_bits(8), Ok(TruncatedDigest::Bits8(0xd0)));
+    assert_eq!(
+        digest.truncate_bits(16),
+        Ok(TruncatedDigest::Bits16(0x07d0))
+    );
+    assert_eq!(
+        digest.truncate_bits(32),
+        Ok(TruncatedDigest::Bits32(0x4b8c07d0))
+    );
+    for bits in [8, 16, 32].iter() {
+        let truncated = digest.truncate_bits(*bits).unwrap();
+        assert_eq!(truncated.bits(), *bits);
+        let size = *bits as usize / 8;
+        assert_eq!(
+            truncated.to_le_bytes(),
+            digest.to_le_bytes()[..size].to_vec()
+        );
+    }
+    for bits in [0, 4, 12, 24, 64].iter() {
+        assert_eq!(
+            digest.truncate_bits(*bits),
+            Err(TruncateError::Width(*bits))
+        );
+    }
+    assert_eq!(
+        digest.truncate_bits(12).unwrap_err().to_string(),
+        "digests truncate to 8, 16 or 32 bits, not 12"
+    );
+}
+
+#[test]
+fn truncations_have_their_own_multihash_codes() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    let expected = [
+        (8, 0x300a01, "poxhash-64-trunc-8"),
+        (16, 0x300a02, "poxhash-64-trunc-16"),
+        (32, 0x300a03, "poxhash-64-trunc-32"),
+    ];
+    for (bits, code, name) in expected.iter() {
+        let multihash = digest.truncate_bits(*bits).unwrap().to_multihash();
+        assert_eq!(multihash.code(), *code);
+        assert_eq!(multihash.name(), *name);
+        assert_eq!(multihash.digest().len(), *bits as usize / 8);
+        assert_eq!(Multihash::from_bytes(&multihash.to_bytes()), Ok(multihash));
+    }
+    assert_eq!(
+        Multihash::from_bytes(&[0x81, 0x94, 0xc0, 0x01, 0x01, 0xd0])
+            .unwrap()
+            .digest(),
+        &[0xd0]
+    );
+}
+
+#[test]
+fn prefixes_resolve_to_one_digest() {
+    let index = small_index();
+    let hex = RadixEncoding::HEX;
+    assert_eq!(index.resolve("f", &hex).unwrap().words[0], 0xfff0);
+    assert_eq!(index.resolve("1234", &hex).unwrap().words[0], 0x1234);
+    assert_eq!(index.resolve("12A", &hex).unwrap().words[0], 0x12ab);
+    assert_eq!(index.resolve("12a", &hex).unwrap().words[0], 0x12ab);
+    assert_eq!(
+        index.resolve("12340000", &hex).unwrap().hexdigest,
+        "1234000000000000"
+    );
+}
+
+#[test]
+fn ambiguous_prefixes_list_the_candidates() {
+    let index = small_index();
+    let hex = RadixEncoding::HEX;
+    let error = index.resolve("123", &hex).err().unwrap();
+    assert_eq!(
+        error,
+        PrefixError::Ambiguous {
+            candidates: vec![
+                "1234000000000000".to_string(),
+                "1235000000000000".to_string()
+            ]
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "ambiguous digest prefix, candidates are 1234000000000000, 1235000000000000"
+    );
+    assert_eq!(
+        index.resolve("1", &hex).err(),
+        Some(PrefixError::Ambiguous {
+            candidates: vec![
+                "1234000000000000".to_string(),
+                "1235000000000000".to_string(),
+                "12AB000000000000".to_string()
+            ]
+        })
+    );
+    assert_eq!(index.resolve("0", &hex).err(), Some(PrefixError::NotFound));
+    assert_eq!(index.resolve("", &hex).err(), Some(PrefixError::Empty));
+}
+
+#[test]
+fn shortest_unique_prefixes() {
+    let index = small_index();
+    let hex = RadixEncoding::HEX;
+    assert_eq!(
+        index.unique_prefixes(&hex),
+        vec!["1234", "1235", "12A", "F"]
+    );
+    let missing = PoxDigest::from_words([0x1236, 0, 0, 0]);
+    assert_eq!(index.unique_prefix_len(&missing, &hex), None);
+    assert_eq!(
+        index.unique_prefix_len(&PoxDigest::from_words([0xfff0, 0, 0, 0]), &hex),
+        Some(1)
+    );
+    assert_eq!(
+        index.unique_prefixes(&RadixEncoding::BIN),
+        vec!["0001001000110100", "0001001000110101", "000100101", "1"]
+    );
+}
+
+fn check_unique_prefixes<E: DigestEncoding>(index: &DigestIndex, encoding: &E) {
+    for (digest, prefix) in index.iter().zip(index.unique_prefixes(encoding)) {
+        assert_eq!(
+            index.resolve(&prefix, encoding).unwrap().words,
+            digest.words
+        );
+        let shorter = &prefix[..prefix.len() - 1];
+        if !shorter.is_empty() {
+            assert!(index.resolve(shorter, encoding).is_err(), "{}", shorter);
+        }
+    }
+}
+
+#[test]
+fn unique_prefixes_resolve_in_every_base() {
+    let mut rng = SplitMix64 { state: 0x35 };
+    let index = (0..500)
+        .map(|_| PoxDigest::from_le_quad(rng.next_u64() & 0xffff_ffff_00ff_ffff))
+        .collect::<DigestIndex>();
+    assert_eq!(index.len(), 500);
+    for encoding in [
+        RadixEncoding::SEX,
+        RadixEncoding::VIG,
+        RadixEncoding::HEX,
+        RadixEncoding::TET,
+        RadixEncoding::DUO,
+        RadixEncoding::OCT,
+        RadixEncoding::SEN,
+        RadixEncoding::BIN,
+        RadixEncoding::BASE36,
+        RadixEncoding::BASE62,
+    ]
+    .iter()
+    {
+        check_unique_prefixes(&index, encoding);
+    }
+    for encoding in [
+        ByteEncoding::Base32,
+        ByteEncoding::Base64Url,
+        ByteEncoding::Base58,
+        ByteEncoding::Proquint,
+    ]
+    .iter()
+    {
+        check_unique_prefixes(&index, encoding);
+    }
+}
+
+#[test]
+fn a_large_index_is_built_and_queried_through_one_encoded_view() {
+    let mut rng = SplitMix64::new(0x100000);
+    let index = (0..100_000)
+        .map(|_| PoxDigest::from_le_quad(rng.next_u64()))
+        .collect::<DigestIndex>();
+    assert_eq!(index.len(), 100_000);
+    let hex = index.encoded(&RadixEncoding::HEX);
+    let prefixes = hex.unique_prefixes();
+    for (digest, prefix) in index.iter().zip(prefixes.iter()).step_by(997) {
+        assert_eq!(hex.resolve(prefix).unwrap().words, digest.words);
+        assert_eq!(hex.unique_prefix_len(digest), Some(prefix.len()));
+    }
+}
+
+#[test]
+fn case_folding_follows_the_encoding() {
+    assert!(!RadixEncoding::HEX.is_case_sensitive());
+    assert!(!RadixEncoding::BASE36.is_case_sensitive());
+    assert!(RadixEncoding::SEX.is_case_sensitive());
+    assert!(RadixEncoding::BASE62.is_case_sensitive());
+    assert!(!ByteEncoding::Base32.is_case_sensitive());
+    assert!(ByteEncoding::Base58.is_case_sensitive());
+
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    let index = vec![digest.clone()].into_iter().collect::<DigestIndex>();
+    assert!(index.resolve("2adyy", &ByteEncoding::Base32).is_ok());
+    assert!(index.resolve("0AeMS", &ByteEncoding::Base64Url).is_ok());
+    assert_eq!(
+        index.resolve("0aems", &ByteEncoding::Base64Url).err(),
+        Some(PrefixError::NotFound)
+    );
+}
+
+#[test]
+fn duplicate_digests_are_stored_once() {
+    let mut index = DigestIndex::new();
+    assert!(index.is_empty());
+    assert!(index.insert(pox_hash(&"PoxHash".as_bytes().to_vec())));
+    assert!(!index.insert(pox_hash(&"PoxHash".as_bytes().to_vec())));
+    assert_eq!(index.len(), 1);
+    assert_eq!(index.unique_prefixes(&RadixEncoding::HEX), vec!["0"]);
+}
+
+#[test]
+fn inserting_after_a_query_is_seen_by_the_next_one() {
+    let mut index = small_index();
+    let hex = RadixEncoding::HEX;
+    assert_eq!(index.resolve("fff", &hex).unwrap().words[0], 0xfff0);
+    assert_eq!(index.unique_prefixes(&hex)[3], "F");
+    assert!(index.insert(PoxDigest::from_words([0xfff1, 0, 0, 0])));
+    assert!(index.resolve("fff", &hex).is_err());
+    assert_eq!(index.unique_prefixes(&hex)[3..], ["FFF0", "FFF1"]);
+    assert_eq!(
+        index.unique_prefix_len(&PoxDigest::from_words([0x1234, 0, 0, 0]), &hex),
+        Some(4)
+    );
+}
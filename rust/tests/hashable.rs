@@ -0,0 +1,273 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                                PoxHashable Tests                                //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+#[macro_use]
+extern crate poxh_derive;
+use libpoxh::{pox_hash, PoxHashable};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(PoxHashable)]
+struct Config {
+    name: String,
+    retries: u8,
+    verbose: bool,
+    tags: Vec<String>,
+    timeout: Option<u32>,
+}
+
+#[derive(PoxHashable)]
+struct Renamed {
+    title: String,
+    retries: u8,
+    verbose: bool,
+    tags: Vec<String>,
+    timeout: Option<u32>,
+}
+
+#[derive(PoxHashable)]
+struct Point(i32, i32);
+
+#[derive(PoxHashable)]
+struct Marker;
+
+#[derive(PoxHashable)]
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { width: u32, height: u32 },
+}
+
+#[derive(PoxHashable)]
+enum ShapeReordered {
+    Rect { width: u32, height: u32 },
+    Circle(u32),
+    Empty,
+}
+
+#[derive(PoxHashable)]
+struct Pair<'a, T, const N: usize>
+where
+    T: Clone,
+{
+    label: &'a str,
+    items: [T; N],
+}
+
+#[derive(PoxHashable)]
+struct Awkward {
+    r#type: u8,
+    out: u8,
+}
+
+fn sample_config() -> Config {
+    Config {
+        name: "poxh".to_string(),
+        retries: 3,
+        verbose: true,
+        tags: vec!["a".to_string(), "bc".to_string()],
+        timeout: None,
+    }
+}
+
+fn encoded_str(text: &str) -> Vec<u8> {
+    let mut bytes = (text.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(text.as_bytes());
+    bytes
+}
+
+#[test]
+fn primitives_have_fixed_encodings() {
+    assert_eq!(0x1234u16.pox_bytes(), vec![0x34, 0x12]);
+    assert_eq!((-2i32).pox_bytes(), vec![0xfe, 0xff, 0xff, 0xff]);
+    assert_eq!(7usize.pox_bytes(), 7u64.pox_bytes());
+    assert_eq!((-7isize).pox_bytes(), (-7i64).pox_bytes());
+    assert_eq!(true.pox_bytes(), vec![1]);
+    assert_eq!(false.pox_bytes(), vec![0]);
+    assert_eq!('€'.pox_bytes(), 0x20acu32.pox_bytes());
+    assert_eq!("ab".pox_bytes(), encoded_str("ab"));
+    assert_eq!("ab".to_string().pox_bytes(), encoded_str("ab"));
+    assert_eq!(().pox_bytes(), Vec::<u8>::new());
+    assert_eq!(std::marker::PhantomData::<u8>.pox_bytes(), Vec::<u8>::new());
+}
+
+#[test]
+fn containers_are_length_prefixed() {
+    let mut expected = 2u64.pox_bytes();
+    expected.extend_from_slice(&[1, 0, 2, 0]);
+    assert_eq!(vec![1u16, 2].pox_bytes(), expected);
+    assert_eq!([1u16, 2].pox_bytes(), expected);
+    let slice: &[u16] = &[1, 2];
+    assert_eq!(PoxHashable::pox_bytes(&slice), expected);
+    assert_eq!(Box::new(vec![1u16, 2]).pox_bytes(), expected);
+    assert_eq!(None::<u8>.pox_bytes(), vec![0]);
+    assert_eq!(Some(9u8).pox_bytes(), vec![1, 9]);
+    assert_eq!((1u8, 2u16).pox_bytes(), vec![1, 2, 0]);
+}
+
+#[test]
+fn concatenation_is_unambiguous() {
+    assert_ne!(("ab", "c").pox_bytes(), ("a", "bc").pox_bytes());
+    assert_ne!(vec!["ab", "c"].pox_bytes(), vec!["a", "bc"].pox_bytes());
+    assert_ne!(
+        (vec![1u8], vec![2u8, 3]).pox_bytes(),
+        (vec![1u8, 2], vec![3u8]).pox_bytes()
+    );
+    assert_ne!(None::<Option<u8>>.pox_bytes(), Some(None::<u8>).pox_bytes());
+    assert_ne!(
+        ("ab", "c").pox_digest().words,
+        ("a", "bc").pox_digest().words
+    );
+}
+
+#[test]
+fn maps_are_sorted_by_key() {
+    let entries = [("zeta", 1u32), ("alpha", 2), ("mid", 3)];
+    let btree = entries.iter().cloned().collect::<BTreeMap<&str, u32>>();
+    let forward = entries.iter().cloned().collect::<HashMap<&str, u32>>();
+    let backward = entries
+        .iter()
+        .rev()
+        .cloned()
+        .collect::<HashMap<&str, u32>>();
+    assert_eq!(forward.pox_bytes(), btree.pox_bytes());
+    assert_eq!(backward.pox_bytes(), btree.pox_bytes());
+
+    let mut expected = 3u64.pox_bytes();
+    for (key, value) in &[("alpha", 2u32), ("mid", 3), ("zeta", 1)] {
+        expected.extend(key.pox_bytes());
+        expected.extend(value.pox_bytes());
+    }
+    assert_eq!(btree.pox_bytes(), expected);
+}
+
+#[test]
+fn derived_structs_encode_field_names_and_values() {
+    let config = sample_config();
+    let mut expected = 5u64.pox_bytes();
+    expected.extend("name".pox_bytes());
+    expected.extend("poxh".pox_bytes());
+    expected.extend("retries".pox_bytes());
+    expected.push(3);
+    expected.extend("verbose".pox_bytes());
+    expected.push(1);
+    expected.extend("tags".pox_bytes());
+    expected.extend(vec!["a", "bc"].pox_bytes());
+    expected.extend("timeout".pox_bytes());
+    expected.push(0);
+    assert_eq!(config.pox_bytes(), expected);
+    assert_eq!(config.pox_digest().words, pox_hash(&expected).words);
+
+    let renamed = Renamed {
+        title: config.name.clone(),
+        retries: config.retries,
+        verbose: config.verbose,
+        tags: config.tags.clone(),
+        timeout: config.timeout,
+    };
+    assert_ne!(renamed.pox_bytes(), config.pox_bytes());
+}
+
+#[test]
+fn derived_digests_are_stable() {
+    assert_eq!(sample_config().pox_digest().hexdigest, "2F132AA328068F76");
+    assert_eq!(
+        Shape::Rect {
+            width: 3,
+            height: 4
+        }
+        .pox_digest()
+        .hexdigest,
+        "2F8E41EA5C797A2D"
+    );
+    let map = vec![(2u8, "b"), (1u8, "a")]
+        .into_iter()
+        .collect::<HashMap<u8, &str>>();
+    assert_eq!(map.pox_digest().hexdigest, "212C9EFD4A0A9E5E");
+}
+
+#[test]
+fn derived_tuple_and_unit_structs() {
+    let mut expected = 2u64.pox_bytes();
+    expected.extend(7i32.pox_bytes());
+    expected.extend((-7i32).pox_bytes());
+    assert_eq!(Point(7, -7).pox_bytes(), expected);
+    assert_ne!(Point(7, -7).pox_bytes(), Point(-7, 7).pox_bytes());
+    assert_eq!(Marker.pox_bytes(), 0u64.pox_bytes());
+}
+
+#[test]
+fn derived_enums_are_tagged_by_variant_name() {
+    let mut expected = "Circle".pox_bytes();
+    expected.extend(1u64.pox_bytes());
+    expected.extend(5u32.pox_bytes());
+    assert_eq!(Shape::Circle(5).pox_bytes(), expected);
+
+    let shapes = [
+        Shape::Empty.pox_bytes(),
+        Shape::Circle(0).pox_bytes(),
+        Shape::Rect {
+            width: 0,
+            height: 0,
+        }
+        .pox_bytes(),
+    ];
+    let reordered = [
+        ShapeReordered::Empty.pox_bytes(),
+        ShapeReordered::Circle(0).pox_bytes(),
+        ShapeReordered::Rect {
+            width: 0,
+            height: 0,
+        }
+        .pox_bytes(),
+    ];
+    assert_eq!(shapes, reordered);
+    assert_ne!(shapes[0], shapes[1]);
+    assert_ne!(shapes[1], shapes[2]);
+}
+
+#[test]
+fn derive_handles_generics_and_awkward_names() {
+    let pair = Pair {
+        label: "xy",
+        items: [1u8, 2, 3],
+    };
+    let mut expected = 2u64.pox_bytes();
+    expected.extend("label".pox_bytes());
+    expected.extend("xy".pox_bytes());
+    expected.extend("items".pox_bytes());
+    expected.extend(vec![1u8, 2, 3].pox_bytes());
+    assert_eq!(pair.pox_bytes(), expected);
+
+    let awkward = Awkward { r#type: 1, out: 2 };
+    let mut expected = 2u64.pox_bytes();
+    expected.extend("type".pox_bytes());
+    expected.push(1);
+    expected.extend("out".pox_bytes());
+    expected.push(2);
+    assert_eq!(awkward.pox_bytes(), expected);
+}
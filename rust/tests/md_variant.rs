@@ -0,0 +1,143 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                                PoxHash-MD Tests                                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, pox_hash_md, Multihash, PoxMdHasher};
+use std::io::Write;
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+// Spot checks from vectors/poxhash-md-vectors-v1.json
+const KNOWN_MD: &[(&str, &str)] = &[
+    ("", "223B7BE4BEBA4498"),
+    ("00", "7A9008D6DFD6A6E6"),
+    ("ff", "FB583D28092FF9DC"),
+];
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn known_messages_hash_as_in_the_vectors() {
+    for (hex, hexdigest) in KNOWN_MD {
+        assert_eq!(
+            pox_hash_md(&from_hex(hex)).digest().hexdigest,
+            *hexdigest,
+            "{}",
+            hex
+        );
+    }
+    assert_eq!(pox_hash_md(&[0x61; 1000]).digest().hexdigest, "8E6ED2E4C1E528C2");
+}
+
+#[test]
+fn streaming_matches_one_shot() {
+    let mut rng = SplitMix64 { state: 0x4d44 };
+    for len in (0..200).chain(vec![1000, 4096]) {
+        let message = rng.bytes(len);
+        let expected = pox_hash_md(&message).into_digest();
+        let mut hasher = PoxMdHasher::new();
+        let mut rest = &message[..];
+        while !rest.is_empty() {
+            let take = std::cmp::min(rest.len(), (rng.next_u64() % 150) as usize);
+            hasher.update(&rest[..take]);
+            rest = &rest[take..];
+        }
+        assert_eq!(
+            hasher.finalize().into_digest().words,
+            expected.words,
+            "{}",
+            len
+        );
+    }
+}
+
+#[test]
+fn hasher_is_an_io_writer() {
+    let message = SplitMix64 { state: 0x10 }.bytes(5000);
+    let mut hasher = PoxMdHasher::default();
+    std::io::copy(&mut &message[..], &mut hasher).unwrap();
+    hasher.write_all(b"").unwrap();
+    assert_eq!(
+        hasher.finalize().digest().words,
+        pox_hash_md(&message).digest().words
+    );
+}
+
+#[test]
+fn padded_message_does_not_collide_with_the_spec() {
+    // M || 0x80 || zeros || len is a multiple of 64 bytes, so octopad leaves it as is and
+    // the spec would run exactly the blocks PoxHash-MD runs for M; only the IV keeps them apart.
+    let mut rng = SplitMix64 { state: 0x80 };
+    for len in &[0, 1, 55, 56, 63, 64, 119, 120, 500] {
+        let message = rng.bytes(*len);
+        let mut padded = message.clone();
+        padded.push(0x80);
+        while padded.len() % 64 != 56 {
+            padded.push(0);
+        }
+        padded.extend_from_slice(&(*len as u64).to_le_bytes());
+        assert_eq!(padded.len() % 64, 0);
+        assert_ne!(
+            pox_hash_md(&message).digest().words,
+            pox_hash(&padded).words,
+            "{}",
+            len
+        );
+    }
+}
+
+#[test]
+fn length_is_part_of_the_padding() {
+    let mut digests = (0..=130)
+        .map(|len| pox_hash_md(&vec![0u8; len]).digest().quad)
+        .collect::<Vec<u64>>();
+    digests.sort_unstable();
+    digests.dedup();
+    assert_eq!(digests.len(), 131);
+}
+
+#[test]
+fn md_digests_have_their_own_multihash_code() {
+    let md = pox_hash_md(b"PoxHash");
+    let multihash = md.to_multihash();
+    assert_eq!(multihash.code(), 0x300a20);
+    assert_eq!(multihash.name(), "poxhash-md-64");
+    assert_eq!(multihash.digest(), &md.digest().to_le_bytes()[..]);
+    assert_ne!(multihash, md.digest().to_multihash());
+    assert_eq!(
+        Multihash::from_bytes(&multihash.to_bytes()).unwrap(),
+        multihash
+    );
+}
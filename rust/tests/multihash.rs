@@ -0,0 +1,245 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                                 Multihash Tests                                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, ByteEncodingError, Multibase, Multihash, MultihashError, PoxDigest};
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+const ALL_BASES: [Multibase; 4] = [
+    Multibase::Base16,
+    Multibase::Base32,
+    Multibase::Base58Btc,
+    Multibase::Base64Url,
+];
+
+// 0x300a00 as an unsigned varint, then the digest length
+const POXHASH_HEADER: [u8; 5] = [0x80, 0x94, 0xc0, 0x01, 0x08];
+
+// PoxHash("PoxHash"); the texts were produced with Python's `base64` module and an
+// independent base58 transcription
+const POXHASH_BYTES: [u8; 8] = [208, 7, 140, 75, 228, 210, 243, 123];
+const POXHASH_TEXTS: [(Multibase, &str); 4] = [
+    (Multibase::Base16, "f8094c00108d0078c4be4d2f37b"),
+    (Multibase::Base32, "bqckmaaii2adyys7e2lzxw"),
+    (Multibase::Base58Btc, "zBiAiD6xpbdC5vWYyrz"),
+    (Multibase::Base64Url, "ugJTAAQjQB4xL5NLzew"),
+];
+
+fn multihash_bytes(code: &[u8], length: u8, digest: &[u8]) -> Vec<u8> {
+    let mut bytes = code.to_vec();
+    bytes.push(length);
+    bytes.extend_from_slice(digest);
+    bytes
+}
+
+#[test]
+fn known_digest_encodes_as_expected() {
+    let multihash = pox_hash(&"PoxHash".as_bytes().to_vec()).to_multihash();
+    assert_eq!(multihash.code(), 0x300a00);
+    assert_eq!(multihash.name(), "poxhash-64");
+    assert_eq!(multihash.digest(), &POXHASH_BYTES[..]);
+    let mut expected = POXHASH_HEADER.to_vec();
+    expected.extend_from_slice(&POXHASH_BYTES);
+    assert_eq!(multihash.to_bytes(), expected);
+    for (base, text) in POXHASH_TEXTS.iter() {
+        assert_eq!(multihash.to_text(*base), *text);
+        assert_eq!(Multihash::from_text(text), Ok(multihash.clone()));
+    }
+    assert_eq!(multihash.to_string(), POXHASH_TEXTS[1].1);
+    assert_eq!(POXHASH_TEXTS[1].1.parse::<Multihash>(), Ok(multihash));
+}
+
+#[test]
+fn random_digests_round_trip() {
+    let mut rng = SplitMix64 { state: 0x3a00 };
+    for _ in 0..1000 {
+        let digest = PoxDigest::from_le_quad(rng.next_u64());
+        let multihash = Multihash::from_digest(&digest);
+        assert_eq!(
+            Multihash::from_bytes(&multihash.to_bytes()),
+            Ok(multihash.clone())
+        );
+        for base in ALL_BASES.iter() {
+            let decoded = Multihash::from_text(&multihash.to_text(*base)).unwrap();
+            assert_eq!(decoded.to_pox_digest().unwrap().hexdigest, digest.hexdigest);
+        }
+    }
+}
+
+#[test]
+fn unknown_codes_are_rejected() {
+    let error = Multihash::from_bytes(&multihash_bytes(&[0x12], 0x20, &[0; 32])).unwrap_err();
+    assert_eq!(error, MultihashError::UnknownCode(0x12));
+    assert_eq!(error.to_string(), "unknown multihash code 0x12");
+    assert_eq!(
+        Multihash::from_bytes(&multihash_bytes(&[0xff, 0x95, 0xc0, 0x01], 8, &[0; 8])),
+        Err(MultihashError::UnknownCode(0x300aff))
+    );
+    assert_eq!(
+        Multihash::new(0x1e, &[0; 32]),
+        Err(MultihashError::UnknownCode(0x1e))
+    );
+}
+
+#[test]
+fn length_mismatches_are_rejected() {
+    let error =
+        Multihash::from_bytes(&multihash_bytes(&POXHASH_HEADER[..4], 8, &[0; 7])).unwrap_err();
+    assert_eq!(
+        error,
+        MultihashError::Truncated {
+            declared: 8,
+            found: 7
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "declared a 8-byte digest but 7 bytes follow"
+    );
+    assert_eq!(
+        Multihash::from_bytes(&multihash_bytes(&POXHASH_HEADER[..4], 8, &[0; 9])),
+        Err(MultihashError::Truncated {
+            declared: 8,
+            found: 9
+        })
+    );
+    assert_eq!(
+        Multihash::from_bytes(&multihash_bytes(&POXHASH_HEADER[..4], 4, &[0; 4])),
+        Err(MultihashError::DigestLength {
+            code: 0x300a00,
+            expected: 8,
+            found: 4
+        })
+    );
+    assert_eq!(
+        Multihash::new(0x300a00, &[0; 16]),
+        Err(MultihashError::DigestLength {
+            code: 0x300a00,
+            expected: 8,
+            found: 16
+        })
+    );
+}
+
+#[test]
+fn malformed_varints_are_rejected() {
+    assert_eq!(Multihash::from_bytes(&[]), Err(MultihashError::Varint));
+    assert_eq!(
+        Multihash::from_bytes(&POXHASH_HEADER[..4]),
+        Err(MultihashError::Varint)
+    );
+    // Unterminated, overlong and non-minimal encodings
+    assert_eq!(
+        Multihash::from_bytes(&[0x80, 0x94, 0xc0]),
+        Err(MultihashError::Varint)
+    );
+    assert_eq!(
+        Multihash::from_bytes(&[0xff; 12]),
+        Err(MultihashError::Varint)
+    );
+    assert_eq!(
+        Multihash::from_bytes(&multihash_bytes(
+            &[0x80, 0x94, 0xc0, 0x81, 0x00],
+            8,
+            &[0; 8]
+        )),
+        Err(MultihashError::Varint)
+    );
+}
+
+#[test]
+fn malformed_text_is_rejected() {
+    assert_eq!(Multihash::from_text(""), Err(MultihashError::EmptyText));
+    let error = Multihash::from_text("Qmabc").unwrap_err();
+    assert_eq!(error, MultihashError::UnknownBase('Q'));
+    assert_eq!(error.to_string(), "unknown multibase prefix `Q`");
+    // Base16 and Base32 are lowercase only
+    assert_eq!(
+        Multihash::from_text("f8094C00108d0078c4be4d2f37b"),
+        Err(MultihashError::Encoding(ByteEncodingError::InvalidChar {
+            position: 4,
+            found: 'C'
+        }))
+    );
+    assert_eq!(
+        Multihash::from_text("f8094c00108d0078c4be4d2f37"),
+        Err(MultihashError::Encoding(ByteEncodingError::TrailingBits))
+    );
+    assert_eq!(
+        Multihash::from_text("z0iAiD6xpbdC5vWYyrz"),
+        Err(MultihashError::Encoding(ByteEncodingError::InvalidChar {
+            position: 0,
+            found: '0'
+        }))
+    );
+}
+
+#[test]
+fn each_full_width_code_converts_only_through_its_own_variant() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    let bytes = Multihash::new(0x300a00, &POXHASH_BYTES).unwrap();
+    let words = Multihash::new(0x300a10, &POXHASH_BYTES).unwrap();
+    let md = Multihash::new(0x300a20, &POXHASH_BYTES).unwrap();
+    assert_eq!(bytes.to_pox_digest().unwrap().hexdigest, digest.hexdigest);
+    assert_eq!(words.to_words_digest().unwrap().hexdigest, digest.hexdigest);
+    assert_eq!(
+        md.to_md_digest().unwrap().digest().hexdigest,
+        digest.hexdigest
+    );
+    for multihash in [&words, &md].iter() {
+        assert_eq!(
+            multihash.to_pox_digest().err(),
+            Some(MultihashError::UnexpectedCode {
+                expected: 0x300a00,
+                found: multihash.code()
+            })
+        );
+    }
+    assert_eq!(
+        bytes.to_md_digest().err(),
+        Some(MultihashError::UnexpectedCode {
+            expected: 0x300a20,
+            found: 0x300a00
+        })
+    );
+    assert_eq!(
+        digest
+            .truncate_bits(32)
+            .unwrap()
+            .to_multihash()
+            .to_pox_digest()
+            .err(),
+        Some(MultihashError::UnexpectedCode {
+            expected: 0x300a00,
+            found: 0x300a03
+        })
+    );
+}
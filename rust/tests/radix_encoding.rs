@@ -0,0 +1,232 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                       RadixEncoding tests for libpoxh.rs                        //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, PoxDigest, RadixEncoding, RadixError};
+
+const SAMPLE_MESSAGES: &'static [&'static str] = &["PoxHash", "PoHxash", "Masud", "Masvd"];
+const CROCKFORD_CHARS: &'static str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const EDGE_WORDS: [u16; 8] = [
+    0x0000, 0x0001, 0x00ff, 0x0100, 0x7fff, 0x8000, 0xfffe, 0xffff,
+];
+
+fn predefined() -> Vec<(&'static str, RadixEncoding)> {
+    vec![
+        ("sex", RadixEncoding::SEX),
+        ("vig", RadixEncoding::VIG),
+        ("hex", RadixEncoding::HEX),
+        ("tet", RadixEncoding::TET),
+        ("duo", RadixEncoding::DUO),
+        ("oct", RadixEncoding::OCT),
+        ("sen", RadixEncoding::SEN),
+        ("bin", RadixEncoding::BIN),
+        ("base36", RadixEncoding::BASE36),
+        ("base62", RadixEncoding::BASE62),
+    ]
+}
+
+fn digest_field<'a>(digest: &'a PoxDigest, name: &str) -> Option<&'a str> {
+    match name {
+        "sex" => Some(&digest.sexdigest),
+        "vig" => Some(&digest.vigdigest),
+        "hex" => Some(&digest.hexdigest),
+        "tet" => Some(&digest.tetdigest),
+        "duo" => Some(&digest.duodigest),
+        "oct" => Some(&digest.octdigest),
+        "sen" => Some(&digest.sendigest),
+        "bin" => Some(&digest.bindigest),
+        _ => None,
+    }
+}
+
+#[test]
+fn predefined_encodings_reproduce_the_digest_fields() {
+    for message in SAMPLE_MESSAGES {
+        let digest = pox_hash(&message.as_bytes().to_vec());
+        for (name, encoding) in predefined() {
+            if let Some(field) = digest_field(&digest, name) {
+                assert_eq!(encoding.encode(&digest), field, "{} of {}", name, message);
+            }
+        }
+    }
+}
+
+#[test]
+fn predefined_widths_match_the_spec() {
+    let widths = predefined()
+        .iter()
+        .map(|(_, encoding)| (encoding.radix(), encoding.width()))
+        .collect::<Vec<(usize, usize)>>();
+    assert_eq!(
+        widths,
+        [
+            (60, 3),
+            (20, 4),
+            (16, 4),
+            (14, 5),
+            (12, 5),
+            (8, 6),
+            (6, 7),
+            (2, 16),
+            (36, 4),
+            (62, 3)
+        ]
+    );
+    for (name, encoding) in predefined() {
+        let recomputed = RadixEncoding::new(encoding.alphabet().to_vec()).unwrap();
+        assert_eq!(recomputed, encoding, "{}", name);
+    }
+}
+
+#[test]
+fn width_is_the_fewest_digits_that_hold_a_word() {
+    for radix in 2..=255usize {
+        let alphabet = (0..radix as u32)
+            .map(|i| std::char::from_u32(0x100 + i).unwrap())
+            .collect::<Vec<char>>();
+        let width = RadixEncoding::new(alphabet).unwrap().width();
+        assert!((radix as u64).pow(width as u32) > 0xffff, "radix {}", radix);
+        assert!(
+            (radix as u64).pow(width as u32 - 1) <= 0xffff,
+            "radix {}",
+            radix
+        );
+    }
+}
+
+#[test]
+fn every_encoding_round_trips_edge_and_sample_words() {
+    let crockford = CROCKFORD_CHARS.parse::<RadixEncoding>().unwrap();
+    let mut encodings = predefined();
+    encodings.push(("crockford", crockford));
+    for (name, encoding) in encodings {
+        let encoded = encoding.encode_words(&EDGE_WORDS);
+        assert_eq!(encoded.chars().count(), EDGE_WORDS.len() * encoding.width());
+        assert_eq!(
+            encoding.decode_words(&encoded).unwrap(),
+            EDGE_WORDS,
+            "{}",
+            name
+        );
+
+        for message in SAMPLE_MESSAGES {
+            let digest = pox_hash(&message.as_bytes().to_vec());
+            let decoded = encoding.decode(&encoding.encode(&digest)).unwrap();
+            assert_eq!(decoded.words, digest.words, "{} of {}", name, message);
+            assert_eq!(
+                decoded.hexdigest, digest.hexdigest,
+                "{} of {}",
+                name, message
+            );
+        }
+    }
+}
+
+#[test]
+fn every_word_round_trips_through_base62() {
+    let words = (0..=u16::MAX).collect::<Vec<u16>>();
+    let encoded = RadixEncoding::BASE62.encode_words(&words);
+    assert_eq!(RadixEncoding::BASE62.decode_words(&encoded).unwrap(), words);
+}
+
+#[test]
+fn custom_alphabets_may_use_any_chars() {
+    let runes = "ᚠᚢᚦᚨᚱᚲᚷᚹ".parse::<RadixEncoding>().unwrap();
+    assert_eq!(runes.width(), 6);
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    let encoded = runes.encode(&digest);
+    let octal = digest
+        .octdigest
+        .chars()
+        .map(|c| runes.alphabet()[c.to_digit(8).unwrap() as usize])
+        .collect::<String>();
+    assert_eq!(encoded, octal);
+    assert_eq!(runes.decode(&encoded).unwrap().words, digest.words);
+}
+
+#[test]
+fn alphabets_are_validated() {
+    assert_eq!(
+        "0".parse::<RadixEncoding>(),
+        Err(RadixError::AlphabetSize(1))
+    );
+    assert_eq!(
+        "".parse::<RadixEncoding>(),
+        Err(RadixError::AlphabetSize(0))
+    );
+    let too_wide = (0..256u32)
+        .map(|i| std::char::from_u32(0x100 + i).unwrap())
+        .collect::<Vec<char>>();
+    let error = RadixEncoding::new(too_wide).unwrap_err();
+    assert_eq!(error, RadixError::AlphabetSize(256));
+    assert_eq!(
+        error.to_string(),
+        "alphabet has 256 chars, expected between 2 and 255"
+    );
+    assert_eq!(
+        "0123456789O0".parse::<RadixEncoding>(),
+        Err(RadixError::RepeatedChar('0'))
+    );
+    assert!(RadixEncoding::new(&['a', 'b'][..]).is_ok());
+}
+
+#[test]
+fn malformed_input_is_rejected_with_its_position() {
+    let hex = RadixEncoding::HEX;
+    assert_eq!(
+        hex.decode("07D04B8CD2E47BF").err(),
+        Some(RadixError::Length {
+            expected: 16,
+            found: 15
+        })
+    );
+    assert_eq!(
+        hex.decode_words("07D04"),
+        Err(RadixError::Ragged { width: 4, found: 5 })
+    );
+    let error = hex.decode("07D04b8CD2E47BF3").unwrap_err();
+    assert_eq!(
+        error,
+        RadixError::InvalidChar {
+            position: 5,
+            found: 'b'
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "`b` at position 5 is not in the alphabet"
+    );
+    assert_eq!(
+        RadixEncoding::SEX.decode_words("0XKxxx"),
+        Err(RadixError::Overflow { position: 3 })
+    );
+    assert_eq!(RadixEncoding::BASE62.decode_words("H31"), Ok(vec![0xffff]));
+    assert_eq!(
+        RadixEncoding::BASE62.decode_words("H31H32"),
+        Err(RadixError::Overflow { position: 3 })
+    );
+}
@@ -0,0 +1,492 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//            libpoxh.rs checked against the spec-literal reference model          //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+// The primitives under test are private to libpoxh.rs, so instead of linking the rlib
+// this file compiles the library source as a child module and reaches in from below.
+
+#[path = "support/reference.rs"]
+mod reference;
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+
+#[allow(dead_code)]
+mod libpoxh {
+    include!("../libpoxh.rs");
+
+    mod against_reference {
+        use super::super::reference;
+        use super::super::splitmix::SplitMix64;
+        use super::{
+            alphabet, block, consts, operations::*, pox_hash, pox_hash_md, pox_hash_words, round,
+            tools, types,
+        };
+
+        const ALPHABET_CASES_NUM: usize = 200_000;
+        const BAHMAN_CASES_NUM: usize = 200_000;
+        const BYTES_CASES_NUM: usize = 200_000;
+        const ROUND_CASES_NUM: usize = 20_000;
+        const BLOCK_CASES_NUM: usize = 64;
+        const OCTOPAD_CASES_NUM: usize = 5_000;
+        const MESSAGE_CASES_NUM: usize = 64;
+        const MESSAGE_MAX_LEN: u64 = 200;
+        // Portions whose averages sit at the top of the u16 range, where `tmt + 3` and
+        // `dca + 2` would overflow in 16 bits
+        const EDGE_PORTIONS: [[u16; 4]; 3] = [
+            [0xffff, 0xfffe, 0x0000, 0x0000],
+            [0xffff, 0xffff, 0xffff, 0xfffb],
+            [0xffff, 0xffff, 0xffff, 0xffff],
+        ];
+        const EDGE_WORDS: [u16; 6] = [0x0000, 0x0001, 0x00ff, 0x0100, 0x7fff, 0xffff];
+        const SEED_ALPHABET: u64 = 0x416c706861626574;
+        const SEED_ROUND: u64 = 0x526f756e64;
+        const SEED_BLOCK: u64 = 0x426c6f636b;
+        const SEED_MESSAGE: u64 = 0x4d657373616765;
+
+        impl SplitMix64 {
+            // Mixes full words with bytes and edge values so `gamma` sees ties and
+            // `bahman` sees factors below the round primes
+            fn word(&mut self) -> u16 {
+                match self.next_u64() % 4 {
+                    0 => EDGE_WORDS[(self.next_u64() % EDGE_WORDS.len() as u64) as usize],
+                    1 => (self.next_u64() & 0xff) as u16,
+                    _ => self.next_u64() as u16,
+                }
+            }
+
+            fn factors(&mut self) -> types::ArrType {
+                [self.word(), self.word(), self.word(), self.word()]
+            }
+        }
+
+        fn widen(factors: &[u16]) -> reference::Factors {
+            [
+                factors[0] as u64,
+                factors[1] as u64,
+                factors[2] as u64,
+                factors[3] as u64,
+            ]
+        }
+
+        fn assert_same(ours: types::ArrType, model: reference::Factors, what: &str, input: &[u16]) {
+            assert_eq!(
+                widen(&ours),
+                model,
+                "{} disagrees with the reference model for {:04x?}",
+                what,
+                input
+            );
+        }
+
+        // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-b-bitwise-operations
+        #[test]
+        fn omega_over_all_doubles() {
+            for num in 0..=u32::MAX {
+                assert_eq!(
+                    bitwise::omega(num) as u64,
+                    reference::omega(num as u64),
+                    "{}",
+                    num
+                );
+            }
+        }
+
+        #[test]
+        fn epsilon_over_all_doubles() {
+            for num in 0..=u32::MAX {
+                assert_eq!(
+                    bitwise::epsilon(num) as u64,
+                    reference::epsilon(num as u64),
+                    "{}",
+                    num
+                );
+            }
+        }
+
+        // `lamed` is only ever handed a word and a rotation of at most one word width
+        #[test]
+        fn lamed_over_all_words_and_rotations() {
+            for by in 0..=16u32 {
+                for num in 0..=u16::MAX as u32 {
+                    assert_eq!(
+                        bitwise::lamed(num, by) as u64,
+                        reference::lamed(num as u64, by as u64),
+                        "lamed({}, {})",
+                        num,
+                        by
+                    );
+                }
+            }
+        }
+
+        // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-c-bespoke-operations
+        #[test]
+        fn gorda_over_all_words_and_rotations() {
+            for by in 0..=16u32 {
+                for num in 0..=u16::MAX {
+                    assert_eq!(
+                        bespoke::gorda(num, by) as u64,
+                        reference::gorda(num as u64, by as u64),
+                        "gorda({}, {})",
+                        num,
+                        by
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn tasu_over_all_word_pairs() {
+            for a in 0..=u16::MAX {
+                for b in 0..=u16::MAX {
+                    if bespoke::tasu(a, b) as u64 != reference::tasu(a as u64, b as u64) {
+                        panic!(
+                            "tasu({}, {}): {} != {}",
+                            a,
+                            b,
+                            bespoke::tasu(a, b),
+                            reference::tasu(a as u64, b as u64)
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn get_8b_prime_over_all_words() {
+            for num in 0..=u16::MAX {
+                assert_eq!(
+                    bespoke::get_8b_prime(num) as u64,
+                    reference::get_8b_prime(num as u64),
+                    "{}",
+                    num
+                );
+            }
+        }
+
+        #[test]
+        fn log2n_over_all_words() {
+            for num in 0..=u16::MAX {
+                assert_eq!(
+                    tools::log2n(num) as u64,
+                    reference::log2n(num as u64),
+                    "{}",
+                    num
+                );
+            }
+        }
+
+        #[test]
+        fn averages_match_on_random_portions() {
+            let mut rng = SplitMix64::new(SEED_ALPHABET ^ 1);
+            for _ in 0..ALPHABET_CASES_NUM {
+                let arr = rng.factors();
+                let weights = [arr[0] % 2, arr[1] % 2, arr[2] % 2, arr[3] % 2];
+                assert_eq!(
+                    bespoke::centum(&arr, &weights) as u64,
+                    reference::centum(&widen(&arr), &widen(&weights))
+                );
+                assert_eq!(
+                    bespoke::satum(&arr, &weights) as u64,
+                    reference::satem(&widen(&arr), &widen(&weights))
+                );
+                assert_eq!(
+                    bespoke::tamaam(&arr) as u64,
+                    reference::tamaam(&widen(&arr))
+                );
+                assert_eq!(bespoke::deca(&arr) as u64, reference::deca(&widen(&arr)));
+            }
+        }
+
+        // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-d-alphabet-operations
+        #[test]
+        fn alphabet_operations_match() {
+            let operations: [(
+                &str,
+                fn(types::ArrTypeRef) -> types::ArrType,
+                fn(&mut reference::Factors),
+            ); 4] = [
+                ("alpha", alphabet::alpha, reference::alpha),
+                ("delta", alphabet::delta, reference::delta),
+                ("theta", alphabet::theta, reference::theta),
+                ("gamma", alphabet::gamma, reference::gamma),
+            ];
+            let mut rng = SplitMix64::new(SEED_ALPHABET);
+            for _ in 0..ALPHABET_CASES_NUM {
+                let factors = rng.factors();
+                for (name, ours, model) in operations.iter() {
+                    let mut expected = widen(&factors);
+                    model(&mut expected);
+                    assert_same(ours(&factors), expected, name, &factors);
+                }
+            }
+        }
+
+        // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-e-round-methods
+        #[test]
+        fn bahman_matches_for_round_and_arbitrary_primes() {
+            let mut rng = SplitMix64::new(SEED_ROUND ^ 1);
+            for i in 0..BAHMAN_CASES_NUM {
+                let factors = rng.factors();
+                let pnum = if i % 2 == 0 {
+                    consts::prime_arrays::ROUND_PRIMES[i / 2 % consts::size_values::ROUND_PRIME_NUM]
+                } else {
+                    rng.word()
+                };
+                let mut expected = widen(&factors);
+                reference::apply_bahman(&mut expected, pnum as u64);
+                assert_same(
+                    round::apply_bahman(&factors, pnum),
+                    expected,
+                    "bahman",
+                    &factors,
+                );
+            }
+        }
+
+        #[test]
+        fn round_steps_match() {
+            let mut rng = SplitMix64::new(SEED_ROUND);
+            for _ in 0..ROUND_CASES_NUM {
+                let factors = rng.factors();
+                let temp = rng.factors();
+
+                let mut expected = widen(&factors);
+                reference::apply_alphabet(&mut expected);
+                assert_same(
+                    round::apply_alphabet_operation(&factors),
+                    expected,
+                    "alphabet",
+                    &factors,
+                );
+
+                let mut expected = widen(&factors);
+                reference::apply_round_prime(&mut expected);
+                assert_same(round::apply_prime(&factors), expected, "prime", &factors);
+
+                let mut expected = widen(&factors);
+                reference::apply_shuffle(&mut expected);
+                assert_same(
+                    round::apply_shuffle(&factors),
+                    expected,
+                    "shuffle",
+                    &factors,
+                );
+
+                let mut expected = widen(&factors);
+                reference::apply_add_temporary(&mut expected, &widen(&temp));
+                assert_same(
+                    round::apply_add_temp_to_facts(&factors, &temp),
+                    expected,
+                    "add",
+                    &factors,
+                );
+
+                let mut expected = widen(&factors);
+                reference::apply_round(&mut expected);
+                assert_same(round::one_round(&factors), expected, "round", &factors);
+            }
+        }
+
+        // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-f-block-methods
+        #[test]
+        fn apply_bytes_matches_for_every_round_index() {
+            let mut rng = SplitMix64::new(SEED_BLOCK ^ 1);
+            for i in 0..BYTES_CASES_NUM {
+                let factors = rng.factors();
+                let portion = rng.bytes(4).iter().map(|b| *b as u16).collect::<Vec<u16>>();
+                let index = (i % consts::size_values::ROUND_NUM) as u16;
+                let mut expected = widen(&factors);
+                reference::apply_bytes_to_factors(&mut expected, &widen(&portion), index as u64);
+                assert_same(
+                    block::apply_bytes(&factors, &portion, index),
+                    expected,
+                    "bytes",
+                    &portion,
+                );
+            }
+        }
+
+        #[test]
+        fn process_block_matches() {
+            let mut rng = SplitMix64::new(SEED_BLOCK);
+            for _ in 0..BLOCK_CASES_NUM {
+                let factors = rng.factors();
+                let block = rng
+                    .bytes(64)
+                    .iter()
+                    .map(|b| *b as u16)
+                    .collect::<Vec<u16>>();
+                let mut expected = widen(&factors);
+                reference::process_block(
+                    &mut expected,
+                    &block.iter().map(|w| *w as u64).collect::<Vec<u64>>(),
+                );
+                assert_same(
+                    block::process_block(&factors, &block),
+                    expected,
+                    "block",
+                    &factors,
+                );
+            }
+        }
+
+        #[test]
+        fn apply_bytes_matches_for_full_words() {
+            let mut rng = SplitMix64::new(SEED_BLOCK ^ 2);
+            for portion in EDGE_PORTIONS.iter() {
+                for index in 0..consts::size_values::ROUND_NUM as u16 {
+                    let factors = rng.factors();
+                    let mut expected = widen(&factors);
+                    reference::apply_bytes_to_factors(&mut expected, &widen(portion), index as u64);
+                    assert_same(
+                        block::apply_bytes(&factors, portion, index),
+                        expected,
+                        "bytes",
+                        portion,
+                    );
+                }
+            }
+            for i in 0..BYTES_CASES_NUM {
+                let factors = rng.factors();
+                let portion = rng.factors();
+                let index = (i % consts::size_values::ROUND_NUM) as u16;
+                let mut expected = widen(&factors);
+                reference::apply_bytes_to_factors(&mut expected, &widen(&portion), index as u64);
+                assert_same(
+                    block::apply_bytes(&factors, &portion, index),
+                    expected,
+                    "bytes",
+                    &portion,
+                );
+            }
+        }
+
+        #[test]
+        fn process_block_matches_for_full_words() {
+            let mut rng = SplitMix64::new(SEED_BLOCK ^ 3);
+            for _ in 0..BLOCK_CASES_NUM {
+                let factors = rng.factors();
+                let block = (0..64).map(|_| rng.word()).collect::<Vec<u16>>();
+                let mut expected = widen(&factors);
+                reference::process_block(
+                    &mut expected,
+                    &block.iter().map(|w| *w as u64).collect::<Vec<u64>>(),
+                );
+                assert_same(
+                    block::process_block(&factors, &block),
+                    expected,
+                    "block",
+                    &factors,
+                );
+            }
+        }
+
+        #[test]
+        fn octopad_matches() {
+            let mut rng = SplitMix64::new(SEED_MESSAGE ^ 1);
+            for len in 0..OCTOPAD_CASES_NUM {
+                let message = rng.bytes(len % 300);
+                let ours = bitwise::octopad(&message);
+                let model = reference::octopad(&message);
+                assert_eq!(
+                    ours.iter().map(|w| *w as u64).collect::<Vec<u64>>(),
+                    model,
+                    "octopad of {} bytes",
+                    message.len()
+                );
+            }
+        }
+
+        // https://github.com/Chubek/PoxHash/blob/master/SPEC.md#the-main-function
+        #[test]
+        fn whole_messages_match() {
+            let mut rng = SplitMix64::new(SEED_MESSAGE);
+            for _ in 0..MESSAGE_CASES_NUM {
+                let len = (rng.next_u64() % MESSAGE_MAX_LEN) as usize;
+                let message = rng.bytes(len);
+                assert_eq!(
+                    widen(&pox_hash(&message).words),
+                    reference::pox_hash(&message),
+                    "message {:?}",
+                    message
+                );
+            }
+        }
+
+        #[test]
+        fn whole_word_messages_match() {
+            let mut rng = SplitMix64::new(SEED_MESSAGE ^ 2);
+            for _ in 0..MESSAGE_CASES_NUM {
+                let len = (rng.next_u64() % MESSAGE_MAX_LEN) as usize;
+                let message = (0..len).map(|_| rng.word()).collect::<Vec<u16>>();
+                assert_eq!(
+                    widen(&pox_hash_words(&message).words),
+                    reference::pox_hash_words(
+                        &message.iter().map(|w| *w as u64).collect::<Vec<u64>>()
+                    ),
+                    "message {:04x?}",
+                    message
+                );
+            }
+        }
+
+        #[test]
+        fn md_padding_and_messages_match() {
+            let mut rng = SplitMix64::new(SEED_MESSAGE ^ 3);
+            for len in 0..OCTOPAD_CASES_NUM {
+                let message = rng.bytes(len % 300);
+                assert_eq!(
+                    bitwise::mdpad(&message, message.len() as u64)
+                        .iter()
+                        .map(|w| *w as u64)
+                        .collect::<Vec<u64>>(),
+                    reference::mdpad(&message),
+                    "mdpad of {} bytes",
+                    message.len()
+                );
+            }
+            for _ in 0..MESSAGE_CASES_NUM {
+                let len = (rng.next_u64() % MESSAGE_MAX_LEN) as usize;
+                let message = rng.bytes(len);
+                assert_eq!(
+                    widen(&pox_hash_md(&message).digest().words),
+                    reference::pox_hash_md(&message),
+                    "message {:?}",
+                    message
+                );
+            }
+        }
+
+        #[test]
+        fn reference_model_reproduces_a_spec_sample() {
+            let words = reference::pox_hash(b"PoxHash");
+            assert_eq!(words, [0x07d0, 0x4b8c, 0xd2e4, 0x7bf3]);
+        }
+    }
+}
@@ -0,0 +1,507 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                                   Runner Tests                                  //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+
+// The runner is a binary with private helpers, so like reference_model.rs this compiles
+// its source as a child module and tests from below.
+
+#[allow(dead_code)]
+mod runner {
+    include!("../runner.rs");
+
+    mod pure_functions {
+        use super::{
+            compare_bench_stats, constant_time_eq, decode_expected, get_bench_stats, get_chart_bar,
+            get_line_lengths, get_sweep_sizes, get_tree_root, glob_matches, hash_files_from,
+            hash_reader, parse_checksum_line, parse_gen_number, parse_gen_spec, parse_int_token,
+            path_matches, to_b64_bytes, to_csv_field, to_hex_bytes, to_json_string,
+            verify_expected, BenchStats, BulkOptions, RecordWriter, SplitMix64, TreeEntry,
+            EXIT_FAILED, TREE_KIND_DIR, TREE_KIND_FILE, TREE_KIND_SYMLINK,
+        };
+        use libpoxh::pox_hash;
+
+        // A fresh directory under the system temp dir for tests that need real files
+        fn scratch_dir(name: &str) -> std::path::PathBuf {
+            let dir =
+                std::env::temp_dir().join(format!("poxh-runner-{}-{}", std::process::id(), name));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            dir
+        }
+
+        fn glob(pattern: &str, text: &str) -> bool {
+            glob_matches(
+                &pattern.chars().collect::<Vec<char>>(),
+                &text.chars().collect::<Vec<char>>(),
+            )
+        }
+
+        fn patterns(patterns: &[&str]) -> Vec<String> {
+            patterns.iter().map(|pattern| pattern.to_string()).collect()
+        }
+
+        fn tree_entry(path: &str, kind: u8, content: &str) -> TreeEntry {
+            TreeEntry {
+                path: path.to_string(),
+                kind,
+                digest: pox_hash(&content.as_bytes().to_vec()),
+            }
+        }
+
+        #[test]
+        fn single_star_stops_at_a_separator() {
+            assert!(glob("*.rs", "main.rs"));
+            assert!(glob("*.rs", ".rs"));
+            assert!(!glob("*.rs", "src/main.rs"));
+            assert!(glob("src/*.rs", "src/main.rs"));
+            assert!(!glob("src/*.rs", "src/bin/main.rs"));
+            assert!(glob("*", ""));
+            assert!(!glob("*", "a/b"));
+        }
+
+        #[test]
+        fn double_star_crosses_separators() {
+            assert!(glob("**/*.rs", "main.rs"));
+            assert!(glob("**/*.rs", "src/main.rs"));
+            assert!(glob("**/*.rs", "src/bin/main.rs"));
+            assert!(glob("src/**/*.rs", "src/main.rs"));
+            assert!(glob("src/**/*.rs", "src/bin/deep/main.rs"));
+            assert!(!glob("src/**/*.rs", "tests/main.rs"));
+            assert!(glob("src/**", "src/bin/main.rs"));
+            assert!(!glob("**/*.rs", "src/main.c"));
+        }
+
+        #[test]
+        fn question_mark_is_one_character_other_than_a_separator() {
+            assert!(glob("?.txt", "a.txt"));
+            assert!(!glob("?.txt", ".txt"));
+            assert!(!glob("?.txt", "ab.txt"));
+            assert!(!glob("a?b", "a/b"));
+            assert!(glob("a?b", "a€b"));
+        }
+
+        #[test]
+        fn patterns_without_a_separator_match_the_basename() {
+            let basename = patterns(&["*.txt"]);
+            assert!(path_matches(&basename, "a.txt"));
+            assert!(path_matches(&basename, "deep/sub/a.txt"));
+            assert!(!path_matches(&basename, "a.txt/b"));
+
+            let anchored = patterns(&["sub/*.txt"]);
+            assert!(path_matches(&anchored, "sub/a.txt"));
+            assert!(!path_matches(&anchored, "deep/sub/a.txt"));
+            assert!(path_matches(&patterns(&["/sub/*.txt"]), "sub/a.txt"));
+
+            let either = patterns(&["*.md", "src/**"]);
+            assert!(path_matches(&either, "docs/README.md"));
+            assert!(path_matches(&either, "src/lib/a.rs"));
+            assert!(!path_matches(&either, "tests/a.rs"));
+        }
+
+        // Pinned from `--recursive` over a directory holding a.txt ("PoxHash"), sub/b.txt
+        // ("oPxHash") and a symbolic link named `link` pointing at a.txt
+        #[test]
+        fn tree_root_of_a_fixed_manifest() {
+            let manifest = |sub: &str, link_kind: u8| {
+                vec![
+                    tree_entry("a.txt", TREE_KIND_FILE, "PoxHash"),
+                    tree_entry("link", link_kind, "a.txt"),
+                    tree_entry("sub", TREE_KIND_DIR, ""),
+                    tree_entry(sub, TREE_KIND_FILE, "oPxHash"),
+                ]
+            };
+            let root = get_tree_root(&manifest("sub/b.txt", TREE_KIND_SYMLINK)).hexdigest;
+            assert_eq!(root, "6B3FF7F5E6F405E5");
+
+            let renamed = manifest("sub/c.txt", TREE_KIND_SYMLINK);
+            assert_ne!(get_tree_root(&renamed).hexdigest, root);
+            let retyped = manifest("sub/b.txt", TREE_KIND_FILE);
+            assert_ne!(get_tree_root(&retyped).hexdigest, root);
+            let mut with_empty_dir = manifest("sub/b.txt", TREE_KIND_SYMLINK);
+            with_empty_dir.push(tree_entry("sub/empty", TREE_KIND_DIR, ""));
+            assert_ne!(get_tree_root(&with_empty_dir).hexdigest, root);
+        }
+
+        #[test]
+        fn checksum_lines_in_both_formats() {
+            assert_eq!(
+                parse_checksum_line(b"07D04B8CD2E47BF3  file name.txt"),
+                Some(("07D04B8CD2E47BF3", &b"file name.txt"[..]))
+            );
+            assert_eq!(
+                parse_checksum_line(b"07D04B8CD2E47BF3 *bin.dat"),
+                Some(("07D04B8CD2E47BF3", &b"bin.dat"[..]))
+            );
+            assert_eq!(
+                parse_checksum_line(b"POXH (a) = b.txt) = 07D04B8CD2E47BF3"),
+                Some(("07D04B8CD2E47BF3", &b"a) = b.txt"[..]))
+            );
+            assert_eq!(
+                parse_checksum_line(b"07D04B8CD2E47BF3  n\xffm"),
+                Some(("07D04B8CD2E47BF3", &b"n\xffm"[..]))
+            );
+            assert_eq!(parse_checksum_line(b"07D04B8CD2E4\xff  x.txt"), None);
+            assert_eq!(parse_checksum_line(b"07D04B8CD2E47BF3 x.txt"), None);
+            assert_eq!(parse_checksum_line(b"07D04B8CD2E47BF3"), None);
+            assert_eq!(parse_checksum_line(b"07D04B8CD2E47BF3  "), None);
+            assert_eq!(parse_checksum_line(b"POXH (x.txt) = "), None);
+            assert_eq!(parse_checksum_line(b"POXH (x.txt)"), None);
+        }
+
+        #[test]
+        fn hex_inputs_skip_separators_in_either_case() {
+            assert_eq!(
+                to_hex_bytes(&"hex=deadbeef".to_string()),
+                vec![0xde, 0xad, 0xbe, 0xef]
+            );
+            assert_eq!(
+                to_hex_bytes(&"hex=DEADbeef".to_string()),
+                vec![0xde, 0xad, 0xbe, 0xef]
+            );
+            assert_eq!(
+                to_hex_bytes(&"hex=de:ad:be:ef".to_string()),
+                vec![0xde, 0xad, 0xbe, 0xef]
+            );
+            assert_eq!(
+                to_hex_bytes(&"hex=dead beef\n".to_string()),
+                vec![0xde, 0xad, 0xbe, 0xef]
+            );
+            assert_eq!(to_hex_bytes(&"hex=0:0".to_string()), vec![0]);
+            assert_eq!(to_hex_bytes(&"hex=".to_string()), Vec::<u8>::new());
+        }
+
+        #[test]
+        fn base64_inputs_accept_both_alphabets_and_padding() {
+            assert_eq!(to_b64_bytes(&"b64=UG94SGFzaA==".to_string()), b"PoxHash");
+            assert_eq!(to_b64_bytes(&"b64=UG94SGFzaA".to_string()), b"PoxHash");
+            assert_eq!(to_b64_bytes(&"b64=UG94 SGFz\naA==".to_string()), b"PoxHash");
+            assert_eq!(to_b64_bytes(&"b64=+/8=".to_string()), vec![0xfb, 0xff]);
+            assert_eq!(to_b64_bytes(&"b64=-_8".to_string()), vec![0xfb, 0xff]);
+            assert_eq!(to_b64_bytes(&"b64=AA==".to_string()), vec![0]);
+        }
+
+        #[test]
+        fn integer_tokens_in_every_base() {
+            assert_eq!(parse_int_token("255", 8), Ok(255));
+            assert_eq!(parse_int_token("0xff", 8), Ok(255));
+            assert_eq!(parse_int_token("0o377", 8), Ok(255));
+            assert_eq!(parse_int_token("0b11111111", 8), Ok(255));
+            assert_eq!(parse_int_token("0xFFFFFFFFFFFFFFFF", 64), Ok(u64::MAX));
+            assert_eq!(
+                parse_int_token("256", 8),
+                Err("does not fit in 8 bits".to_string())
+            );
+            assert_eq!(
+                parse_int_token("0x10000000000000000", 64),
+                Err("does not fit in 64 bits".to_string())
+            );
+            assert_eq!(parse_int_token("0x", 16), Err("has no digits".to_string()));
+            assert_eq!(parse_int_token("", 16), Err("has no digits".to_string()));
+            assert_eq!(
+                parse_int_token("0b102", 16),
+                Err("has `2`, which is not a base 2 digit".to_string())
+            );
+            assert_eq!(
+                parse_int_token("-1", 32),
+                Err("has `-`, which is not a base 10 digit".to_string())
+            );
+        }
+
+        #[test]
+        fn json_strings_escape_quotes_backslashes_and_controls() {
+            assert_eq!(to_json_string("file.txt"), "\"file.txt\"");
+            assert_eq!(to_json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+            assert_eq!(to_json_string("\n\r\t"), "\"\\n\\r\\t\"");
+            assert_eq!(to_json_string("\u{0}\u{1f}"), "\"\\u0000\\u001f\"");
+            assert_eq!(to_json_string("\u{7f}€"), "\"\u{7f}€\"");
+        }
+
+        #[test]
+        fn csv_fields_are_quoted_only_when_needed() {
+            assert_eq!(to_csv_field("file.txt"), "file.txt");
+            assert_eq!(to_csv_field("a b\tc"), "a b\tc");
+            assert_eq!(to_csv_field("a,b"), "\"a,b\"");
+            assert_eq!(to_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+            assert_eq!(to_csv_field("two\nlines"), "\"two\nlines\"");
+            assert_eq!(to_csv_field("cr\r"), "\"cr\r\"");
+            assert_eq!(to_csv_field(""), "");
+        }
+
+        #[test]
+        fn constant_time_eq_compares_length_and_every_byte() {
+            assert!(constant_time_eq(b"", b""));
+            assert!(constant_time_eq(b"PoxHash", b"PoxHash"));
+            assert!(!constant_time_eq(b"PoxHash", b"PoxHasH"));
+            assert!(!constant_time_eq(b"PoxHash", b"PoxHas"));
+            assert!(!constant_time_eq(b"", b"P"));
+        }
+
+        #[test]
+        fn expected_digests_decode_in_the_base_that_accepts_them() {
+            let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+            let encoded = [
+                ("sexdigest", digest.sexdigest.clone()),
+                ("vigdigest", digest.vigdigest.clone()),
+                ("hexdigest", digest.hexdigest.to_lowercase()),
+                ("tetdigest", digest.tetdigest.clone()),
+                ("duodigest", digest.duodigest.clone()),
+                ("octdigest", digest.octdigest.clone()),
+                ("sendigest", digest.sendigest.clone()),
+                ("bindigest", digest.bindigest.clone()),
+            ];
+            for (field, expected) in encoded.iter() {
+                let decoded = decode_expected(expected);
+                assert_eq!(decoded.len(), 1, "{}", field);
+                assert_eq!(decoded[0].0, *field);
+                assert_eq!(decoded[0].1.bytes, digest.bytes, "{}", field);
+            }
+            assert!(decode_expected("not a digest").is_empty());
+            assert!(decode_expected("07D04B8CD2E47BF").is_empty());
+        }
+
+        #[test]
+        fn a_mismatched_expectation_fails_with_its_exit_status() {
+            let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+            let other = pox_hash(&"oPxHash".as_bytes().to_vec());
+            assert_eq!(
+                verify_expected(&"07D04B8CD2E47BF3".to_string(), &digest, true),
+                0
+            );
+            assert_eq!(verify_expected(&digest.sexdigest, &digest, true), 0);
+            assert_eq!(
+                verify_expected(&"07D04B8CD2E47BF3".to_string(), &other, true),
+                EXIT_FAILED
+            );
+            assert_eq!(
+                verify_expected(&"not a digest".to_string(), &digest, true),
+                EXIT_FAILED
+            );
+        }
+
+        fn bench_stats(mean: f64, stddev: f64, samples: usize) -> BenchStats {
+            BenchStats {
+                label: "PoxHash".to_string(),
+                length: 7,
+                samples,
+                min: mean,
+                median: mean,
+                mean,
+                stddev,
+                p99: mean,
+            }
+        }
+
+        #[test]
+        fn bench_stats_summarize_unsorted_samples() {
+            let stats = get_bench_stats("odd".to_string(), 7, vec![5.0, 1.0, 3.0, 2.0, 4.0]);
+            assert_eq!(stats.samples, 5);
+            assert_eq!(stats.min, 1.0);
+            assert_eq!(stats.median, 3.0);
+            assert_eq!(stats.mean, 3.0);
+            assert_eq!(stats.stddev, 2.5f64.sqrt());
+            assert_eq!(stats.p99, 5.0);
+
+            let even = get_bench_stats("even".to_string(), 7, vec![4.0, 1.0, 3.0, 2.0]);
+            assert_eq!(even.median, 2.5);
+
+            let hundreds = (1..=200).rev().map(|ns| ns as f64).collect::<Vec<f64>>();
+            let stats = get_bench_stats("hundreds".to_string(), 7, hundreds);
+            assert_eq!(stats.min, 1.0);
+            assert_eq!(stats.median, 100.5);
+            assert_eq!(stats.p99, 198.0);
+        }
+
+        #[test]
+        fn bench_comparisons_test_the_difference_of_means() {
+            assert_eq!(
+                compare_bench_stats(&bench_stats(110.0, 1.0, 100), &bench_stats(100.0, 1.0, 100)),
+                "10.00% slower (significant, t = 70.71)"
+            );
+            assert_eq!(
+                compare_bench_stats(&bench_stats(100.1, 10.0, 10), &bench_stats(100.0, 10.0, 10)),
+                "no significant change, 0.10% slower (t = 0.02)"
+            );
+            assert_eq!(
+                compare_bench_stats(&bench_stats(90.0, 0.0, 10), &bench_stats(100.0, 0.0, 10)),
+                "10.00% faster (no variance to test)"
+            );
+        }
+
+        #[test]
+        fn sweep_sizes_add_the_block_boundaries() {
+            assert_eq!(
+                get_sweep_sizes(1024, "log", 11),
+                vec![1, 2, 4, 8, 16, 32, 63, 64, 65, 128, 256, 512, 1024]
+            );
+            assert_eq!(
+                get_sweep_sizes(100, "linear", 5),
+                vec![1, 26, 51, 63, 64, 65, 75, 100]
+            );
+            assert_eq!(
+                get_sweep_sizes(64, "log", 7),
+                vec![1, 2, 4, 8, 16, 32, 63, 64]
+            );
+            assert_eq!(get_sweep_sizes(10, "log", 2), vec![1, 10]);
+        }
+
+        #[test]
+        fn chart_bars_round_to_eighths_of_a_block() {
+            assert_eq!(get_chart_bar(320.0, 320.0, false), "█".repeat(40));
+            assert_eq!(get_chart_bar(160.0, 320.0, false), "█".repeat(20));
+            assert_eq!(get_chart_bar(12.0, 320.0, false), "█▌");
+            assert_eq!(get_chart_bar(1.0, 320.0, false), "▏");
+            assert_eq!(get_chart_bar(0.0, 320.0, false), "");
+            assert_eq!(get_chart_bar(5.0, 0.0, false), "");
+            assert_eq!(get_chart_bar(320.0, 320.0, true), "#".repeat(40));
+            assert_eq!(get_chart_bar(12.0, 320.0, true), "##");
+            assert_eq!(get_chart_bar(1.0, 320.0, true), "#");
+            assert_eq!(get_chart_bar(0.0, 320.0, true), "");
+        }
+
+        fn generated(spec: &str) -> Vec<u8> {
+            let mut bytes = vec![];
+            std::io::Read::read_to_end(&mut parse_gen_spec(spec), &mut bytes).unwrap();
+            bytes
+        }
+
+        #[test]
+        fn generator_sizes_take_binary_suffixes() {
+            assert_eq!(parse_gen_number("0", "zeros:0"), 0);
+            assert_eq!(parse_gen_number("10", "zeros:10"), 10);
+            assert_eq!(parse_gen_number("4K", "zeros:4K"), 4096);
+            assert_eq!(parse_gen_number("2m", "zeros:2m"), 2 << 20);
+            assert_eq!(parse_gen_number("3G", "zeros:3G"), 3 << 30);
+        }
+
+        #[test]
+        fn generators_produce_their_documented_bytes() {
+            assert_eq!(generated("zeros:3"), vec![0, 0, 0]);
+            assert_eq!(generated("zeros:0"), Vec::<u8>::new());
+            let counter = generated("counter:258");
+            assert_eq!(counter.len(), 258);
+            assert_eq!(&counter[..3], &[0, 1, 2]);
+            assert_eq!(&counter[255..], &[255, 0, 1]);
+            assert_eq!(generated("repeat:ab:c:5"), b"ab:ca");
+
+            let mut rng = SplitMix64::new(7);
+            let mut expected = rng.next_u64().to_le_bytes().to_vec();
+            expected.extend_from_slice(&rng.next_u64().to_le_bytes()[..4]);
+            assert_eq!(generated("rand:7:12"), expected);
+        }
+
+        #[test]
+        fn generated_streams_hash_as_pinned() {
+            let (digest, length) = hash_reader(&mut parse_gen_spec("rand:7:1000"));
+            assert_eq!(
+                (digest.hexdigest.as_str(), length),
+                ("E48F9D5AA19028B5", 1000)
+            );
+            assert_eq!(hash_reader(&mut parse_gen_spec("zeros:1K")).1, 1024);
+        }
+
+        #[test]
+        fn lines_drop_their_terminator_unless_asked_to_keep_it() {
+            let plain = BulkOptions::default();
+            assert_eq!(get_line_lengths(&plain, b"PoxHash\n"), Some((7, 7)));
+            assert_eq!(get_line_lengths(&plain, b"PoxHash"), Some((7, 7)));
+            assert_eq!(get_line_lengths(&plain, b"PoxHash\r\n"), Some((8, 8)));
+            assert_eq!(get_line_lengths(&plain, b"\n"), Some((0, 0)));
+
+            let keep = BulkOptions {
+                keep_newlines: true,
+                ..Default::default()
+            };
+            assert_eq!(get_line_lengths(&keep, b"PoxHash\n"), Some((7, 8)));
+            assert_eq!(get_line_lengths(&keep, b"PoxHash"), Some((7, 7)));
+            assert_eq!(get_line_lengths(&keep, b"\n"), Some((0, 1)));
+        }
+
+        #[test]
+        fn crlf_strips_a_carriage_return_only_before_a_newline() {
+            let crlf = BulkOptions {
+                crlf: true,
+                ..Default::default()
+            };
+            assert_eq!(get_line_lengths(&crlf, b"PoxHash\r\n"), Some((7, 7)));
+            assert_eq!(get_line_lengths(&crlf, b"PoxHash\r"), Some((8, 8)));
+            assert_eq!(get_line_lengths(&crlf, b"Pox\rHash\n"), Some((8, 8)));
+            assert_eq!(get_line_lengths(&crlf, b"\r\n"), Some((0, 0)));
+
+            let keep = BulkOptions {
+                crlf: true,
+                keep_newlines: true,
+                ..Default::default()
+            };
+            assert_eq!(get_line_lengths(&keep, b"PoxHash\r\n"), Some((7, 9)));
+        }
+
+        #[test]
+        fn skip_empty_drops_lines_with_no_content() {
+            let skip = BulkOptions {
+                skip_empty: true,
+                crlf: true,
+                keep_newlines: true,
+                ..Default::default()
+            };
+            assert_eq!(get_line_lengths(&skip, b"\n"), None);
+            assert_eq!(get_line_lengths(&skip, b"\r\n"), None);
+            assert_eq!(get_line_lengths(&skip, b" \n"), Some((1, 2)));
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn files_from_opens_names_that_are_not_utf8() {
+            use std::os::unix::ffi::OsStrExt;
+
+            let dir = scratch_dir("files-from");
+            let mut listed = dir.as_os_str().as_bytes().to_vec();
+            listed.extend_from_slice(b"/n\xffm");
+            std::fs::write(std::ffi::OsStr::from_bytes(&listed), b"PoxHash").unwrap();
+            let mut list = listed.clone();
+            list.push(b'\0');
+            list.extend_from_slice(dir.join("absent").as_os_str().as_bytes());
+            list.push(b'\0');
+            let list_path = dir.join("list");
+            std::fs::write(&list_path, list).unwrap();
+
+            let options = BulkOptions {
+                files_from: Some(list_path.to_string_lossy().to_string()),
+                null: true,
+                ..Default::default()
+            };
+            let mut writer = RecordWriter::new(&"-h-".to_string());
+            let missing = hash_files_from(
+                &options,
+                &mut writer,
+                &list_path.to_string_lossy().to_string(),
+            );
+            assert_eq!(missing, 1);
+            let _ = std::fs::remove_dir_all(&dir);
+        }
+    }
+}
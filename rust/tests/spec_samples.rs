@@ -0,0 +1,241 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                 SPEC.md sample conformance tests for libpoxh.rs                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, PoxDigest};
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-g3-non-decimal-digests-letter-convention
+// The letter-digits are transcribed here instead of being borrowed from the library, so a
+// broken table in `convert` cannot agree with itself.
+const SEX_CHARS: &'static str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwx";
+const VIG_CHARS: &'static str = "ABCDEFGHIJ@^&*$+!;:~";
+const HEX_CHARS: &'static str = "0123456789ABCDEF";
+const TET_CHARS: &'static str = "0123456789TEWR";
+const DUO_CHARS: &'static str = "0123456789*#";
+const OCT_CHARS: &'static str = "01234567";
+const SEN_CHARS: &'static str = "012345";
+const BIN_CHARS: &'static str = "01";
+
+const FIXTURE_RFC2616: &'static str = "fixtures/rfc2616.txt";
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#long-string-messages
+// RFC 2616 is not vendored, so these stand in for it until it has been fetched.
+const RFC2616_HEXDIGEST: &'static str = "43B9A61E5A9A5441";
+const RFC2616_PRIME_HEXDIGEST: &'static str = "332BF0AEDC518AF1";
+const PUBLISHED_SAMPLES: &'static [&'static str] = &[include_str!("../../SPEC.md"), include_str!("../../README.md")];
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#short-string-messages
+const SHORT_STRING_SAMPLES: &'static [(&'static str, &'static str)] = &[
+    ("PoxHash", "07D04B8CD2E47BF3"),
+    ("oPxHash", "8F7D20ECC51F3285"),
+    ("PoxaHsh", "547CBDB0CB569320"),
+    ("PxoHash", "D92204E8D1C90376"),
+    ("PoxHahs", "8D1DFF6A365C6E1A"),
+    ("QoxHash", "74B3D0533F14145B"),
+    ("PpxHash", "E6DD8876150D0CBA"),
+    ("PoyHash", "337BA5F968A3927E"),
+    ("PoxIash", "61C8B88057481B42"),
+];
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#byte-arrays
+const BYTE_ARRAY_SAMPLES: &'static [(&'static [u8], &'static str)] = &[
+    (
+        &[0b00100010, 0b01100101, 0b10110101, 0b10110101, 0b01011101, 0b1111110, 0b1111101],
+        "41BA2FB4D6421610",
+    ),
+    (
+        &[0b00100010, 0b01100100, 0b10110101, 0b10110101, 0b01011101, 0b1111110, 0b1111101],
+        "1568B6F5F5948EF6",
+    ),
+    (
+        &[0b00100010, 0b01100101, 0b10110101, 0b10110101, 0b01011101, 0b1101110, 0b1111101],
+        "6293E59B2064CD28",
+    ),
+    (
+        &[0b00100010, 0b01100101, 0b10110101, 0b10111101, 0b01011101, 0b1111110, 0b1111101],
+        "8D91A7BC753E223D",
+    ),
+];
+
+fn decode_base(digest: &str, chars: &str, width: usize) -> Vec<u16> {
+    let base = chars.chars().count() as u32;
+    let digits = digest.chars().collect::<Vec<char>>();
+    assert_eq!(digits.len(), width * 4, "`{}` has the wrong width", digest);
+    digits
+        .chunks(width)
+        .map(|chunk| {
+            let mut word = 0u32;
+            for digit in chunk {
+                let value = chars
+                    .chars()
+                    .position(|c| c == *digit)
+                    .unwrap_or_else(|| panic!("`{}` is not a digit of base {}", digit, base));
+                word = word * base + value as u32;
+            }
+            assert!(word <= 0xffff, "`{}` overflows a word", digest);
+            word as u16
+        })
+        .collect()
+}
+
+fn assert_consistent(digest: &PoxDigest) {
+    let words = digest.words.to_vec();
+    assert_eq!(decode_base(&digest.sexdigest, SEX_CHARS, 3), words, "sexdigest");
+    assert_eq!(decode_base(&digest.vigdigest, VIG_CHARS, 4), words, "vigdigest");
+    assert_eq!(decode_base(&digest.hexdigest, HEX_CHARS, 4), words, "hexdigest");
+    assert_eq!(decode_base(&digest.tetdigest, TET_CHARS, 5), words, "tetdigest");
+    assert_eq!(decode_base(&digest.duodigest, DUO_CHARS, 5), words, "duodigest");
+    assert_eq!(decode_base(&digest.octdigest, OCT_CHARS, 6), words, "octdigest");
+    assert_eq!(decode_base(&digest.sendigest, SEN_CHARS, 7), words, "sendigest");
+    assert_eq!(decode_base(&digest.bindigest, BIN_CHARS, 16), words, "bindigest");
+
+    for i in 0..4 {
+        assert_eq!(digest.bytes[i * 2] as u16, words[i] & 0x00ff, "bytes[{}]", i * 2);
+        assert_eq!(digest.bytes[i * 2 + 1] as u16, words[i] >> 8, "bytes[{}]", i * 2 + 1);
+    }
+    assert_eq!(digest.doubles[0], words[0] as u32 | (words[1] as u32) << 16, "doubles[0]");
+    assert_eq!(digest.doubles[1], words[2] as u32 | (words[3] as u32) << 16, "doubles[1]");
+    assert_eq!(
+        digest.quad,
+        digest.doubles[0] as u64 | (digest.doubles[1] as u64) << 32,
+        "quad"
+    );
+}
+
+fn assert_sample(message: &[u8], hexdigest: &str) {
+    let digest = pox_hash(&message.to_vec());
+    assert_eq!(digest.hexdigest, hexdigest);
+    assert_consistent(&digest);
+}
+
+fn fixture_path(name: &str) -> std::path::PathBuf {
+    std::path::Path::new(file!()).with_file_name(name)
+}
+
+#[test]
+fn short_string_messages() {
+    for (message, hexdigest) in SHORT_STRING_SAMPLES {
+        assert_sample(message.as_bytes(), hexdigest);
+    }
+}
+
+#[test]
+fn byte_array_messages() {
+    for (message, hexdigest) in BYTE_ARRAY_SAMPLES {
+        assert_sample(message, hexdigest);
+    }
+}
+
+#[test]
+fn long_message_a() {
+    assert_sample(
+        include_bytes!("fixtures/example_com.html"),
+        "53F0C77BD979E4C9",
+    );
+}
+
+#[test]
+fn long_message_a_prime() {
+    let message = include_bytes!("fixtures/example_com_prime.html");
+    assert_sample(message, "DC1119514D34DD17");
+}
+
+#[test]
+fn long_message_a_prime_is_derived_from_a() {
+    let original = String::from_utf8(include_bytes!("fixtures/example_com.html").to_vec()).unwrap();
+    let altered = include_bytes!("fixtures/example_com_prime.html");
+    assert_eq!(original.replacen("<html>", "<htlm>", 1).as_bytes(), &altered[..]);
+}
+
+// The published digests are always checked, the RFC itself only once it has been fetched
+#[test]
+fn long_messages_b_and_b_prime() {
+    for published in PUBLISHED_SAMPLES {
+        assert!(published.contains(&format!("| B       | {} |", RFC2616_HEXDIGEST)));
+        assert!(published.contains(&format!("| B'      | {} |", RFC2616_PRIME_HEXDIGEST)));
+    }
+
+    let path = fixture_path(FIXTURE_RFC2616);
+    let message = match std::fs::read(&path) {
+        Ok(message) => message,
+        Err(err) => {
+            eprintln!("{}: {}, B and B' were not hashed, see tests/fixtures/README.md", path.display(), err);
+            return;
+        }
+    };
+    assert_sample(&message, RFC2616_HEXDIGEST);
+
+    let altered = String::from_utf8(message)
+        .unwrap()
+        .replacen("[Page 116]", "[Page 161]", 1);
+    assert_sample(altered.as_bytes(), RFC2616_PRIME_HEXDIGEST);
+}
+
+// The "A Final Look" listing in RUNNERS.md prints every view of these two messages
+#[test]
+fn every_view_of_runner_samples() {
+    let digest = pox_hash(&"PoxHash".as_bytes().to_vec());
+    assert_eq!(digest.bytes, [208, 7, 140, 75, 228, 210, 243, 123]);
+    assert_eq!(digest.words, [2000, 19340, 53988, 31731]);
+    assert_eq!(digest.doubles, [1267468240, 2079576804]);
+    assert_eq!(digest.quad, 8931714363967670224);
+    assert_eq!(digest.sexdigest, "0XK5MKExm8mp");
+    assert_eq!(digest.vigdigest, "AFAACIHAG$~ID~G^");
+    assert_eq!(digest.hexdigest, "07D04B8CD2E47BF3");
+    assert_eq!(digest.tetdigest, "00T2W07096159640E7W7");
+    assert_eq!(digest.duodigest, "011*80#238272#016443");
+    assert_eq!(digest.octdigest, "003720045614151344075763");
+    assert_eq!(digest.sendigest, "0013132022531210535400402523");
+    assert_eq!(
+        digest.bindigest,
+        "0000011111010000010010111000110011010010111001000111101111110011"
+    );
+
+    let digest = pox_hash(&"PoHxash".as_bytes().to_vec());
+    assert_eq!(digest.bytes, [247, 15, 107, 190, 189, 227, 79, 159]);
+    assert_eq!(digest.words, [4087, 48747, 58301, 40783]);
+    assert_eq!(digest.doubles, [3194687479, 2672812989]);
+    assert_eq!(digest.quad, 11479644379273695223);
+    assert_eq!(digest.sexdigest, "187DWRGBfBJh");
+    assert_eq!(digest.vigdigest, "A@EHGB;HHF+BFB~D");
+    assert_eq!(digest.hexdigest, "0FF7BE6BE3BD9F4F");
+    assert_eq!(digest.tetdigest, "016ER13T9R1736510W11");
+    assert_eq!(digest.duodigest, "0244724263298*51#727");
+    assert_eq!(digest.octdigest, "007767137153161675117517");
+    assert_eq!(digest.sendigest, "0030531101340311255250512451");
+    assert_eq!(
+        digest.bindigest,
+        "0000111111110111101111100110101111100011101111011001111101001111"
+    );
+}
+
+#[test]
+fn empty_message_is_the_initial_primes() {
+    let digest = pox_hash(&Vec::new());
+    assert_eq!(digest.words, [0x17cb, 0x0371, 0x2419, 0xf223]);
+    assert_consistent(&digest);
+}
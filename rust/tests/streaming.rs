@@ -0,0 +1,112 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                             Streaming Hasher Tests                              //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, PoxHasher};
+use std::io::Write;
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+// octopad never reads further into the message than this
+const OCTOPAD_REACH: usize = 16320;
+
+fn stream_in_chunks(message: &[u8], rng: &mut SplitMix64, max_chunk: u64) -> [u16; 4] {
+    let mut hasher = PoxHasher::new();
+    let mut rest = message;
+    while !rest.is_empty() {
+        let take = std::cmp::min(rest.len(), (rng.next_u64() % max_chunk) as usize);
+        hasher.update(&rest[..take]);
+        rest = &rest[take..];
+    }
+    hasher.finalize().words
+}
+
+#[test]
+fn streaming_matches_one_shot() {
+    let mut rng = SplitMix64 { state: 0x5354 };
+    for len in 0..200 {
+        let message = rng.bytes(len);
+        assert_eq!(
+            stream_in_chunks(&message, &mut rng, 80),
+            pox_hash(&message).words,
+            "{}",
+            len
+        );
+    }
+}
+
+#[test]
+fn padding_past_the_kept_head_matches() {
+    let mut rng = SplitMix64 { state: 0x4f50 };
+    // all-0xff messages make octopad step as far as it can
+    for len in &[
+        OCTOPAD_REACH - 1,
+        OCTOPAD_REACH + 1,
+        OCTOPAD_REACH + 63,
+        20001,
+    ] {
+        let saturated = vec![0xffu8; *len];
+        assert_eq!(
+            stream_in_chunks(&saturated, &mut rng, 5000),
+            pox_hash(&saturated).words,
+            "{}",
+            len
+        );
+        let random = rng.bytes(*len);
+        assert_eq!(
+            stream_in_chunks(&random, &mut rng, 5000),
+            pox_hash(&random).words,
+            "{}",
+            len
+        );
+    }
+}
+
+#[test]
+fn empty_and_whole_block_messages() {
+    assert_eq!(
+        PoxHasher::new().finalize().words,
+        pox_hash(&Vec::new()).words
+    );
+    let mut hasher = PoxHasher::default();
+    hasher.update(&[]);
+    hasher.update(&[7; 64]);
+    hasher.update(&[]);
+    assert_eq!(hasher.finalize().words, pox_hash(&vec![7; 64]).words);
+}
+
+#[test]
+fn hasher_is_an_io_writer() {
+    let message = SplitMix64 { state: 0x10 }.bytes(5000);
+    let mut hasher = PoxHasher::new();
+    std::io::copy(&mut &message[..], &mut hasher).unwrap();
+    hasher.flush().unwrap();
+    assert_eq!(hasher.finalize().hexdigest, pox_hash(&message).hexdigest);
+}
@@ -0,0 +1,473 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                 Spec-literal reference model of PoxHash in Rust                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+// A deliberately naive transcription of the pseudo-code in SPEC.md, kept apart from
+// libpoxh.rs so the two can be compared. Every spec variable is an unbounded `int`
+// (u64 here) and factors are only checked to still fit in a word after each step.
+// Nothing is shared with the library: constants are copied from SPEC.md part A.
+//
+// Where the pseudo-code has a typo that all six ports and the published samples
+// disagree with, the model follows the ports and the line carries an `ERRATUM` note.
+
+pub type Factors = [u64; 4];
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#initial-prime-numbers
+pub const PRIME_INIT_A: u64 = 0x17cb;
+pub const PRIME_INIT_B: u64 = 0x0371;
+pub const PRIME_INIT_C: u64 = 0x2419;
+pub const PRIME_INIT_D: u64 = 0xf223;
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#size-constants
+pub const ROUND_PRIME_NUM: u64 = 90;
+pub const BLOCK_NUM: u64 = 64;
+pub const B8_PRIME_NUM: u64 = 54;
+pub const ROUND_NUM: u64 = 31;
+pub const CHUNK_NUM: u64 = 16;
+pub const PORTION_NUM: u64 = 4;
+pub const MASKS_NUM: u64 = 4;
+pub const SD_PRIME_NUM: u64 = 3;
+pub const MAGIC_PRIME_NUM: u64 = 2;
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#bit-related-constants
+pub const WORD_WIDTH: u64 = 16;
+pub const MAX_UINT16: u64 = 65535;
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#masks
+pub const QWORD_14Z2F: u64 = 0x00000000000000ff;
+pub const DWORD_4F4Z: u64 = 0xffff0000;
+pub const DWORD_4Z4F: u64 = 0x0000ffff;
+pub const WORD_FZFZ: u64 = 0xf0f0;
+pub const WORD_ZFZF: u64 = 0x0f0f;
+pub const WORD_FZZZ: u64 = 0xf000;
+pub const WORD_ZZFZ: u64 = 0x00f0;
+pub const WORD_ZZZF: u64 = 0x000f;
+pub const WORD_ZZFF: u64 = 0x00ff;
+pub const WORD_FFZZ: u64 = 0xff00;
+pub const WORD_FZZF: u64 = 0xf00f;
+pub const WORD_FFFZ: u64 = 0xfff0;
+pub const WORD_ZFFF: u64 = 0x0fff;
+pub const NIBBLET_01: u64 = 0b01;
+pub const NIBBLET_10: u64 = 0b10;
+pub const NIBBLET_11: u64 = 0b11;
+pub const NIBBLET_00: u64 = 0b00;
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#prime-arrays
+// ERRATUM: declared as `{u16, 32}`, but the table holds 90 primes
+pub const ROUND_PRIMES: [u64; 90] = [
+    0x0377, 0x0683, 0x05fb, 0x05fb, 0x0665, 0x074b, 0x036d, 0x033d, 0x0115, 0x07cf, 0x0e59, 0x0e75,
+    0x0a75, 0x119b, 0x1073, 0x12b3, 0x0fd1, 0x0a75, 0x0de7, 0x10bb, 0x18d1, 0x1c99, 0x1723, 0x1cc9,
+    0x20c3, 0x2327, 0x2063, 0x215b, 0x17e1, 0x22bd, 0xf2ff, 0xf50b, 0xf4af, 0xf2b3, 0xf5fb, 0xf4af,
+    0xf2b9, 0xf38b, 0xf4c3, 0xf5db, 0x1039, 0x1003, 0x0fa1, 0x0fa3, 0x0fa7, 0x8687, 0x80db, 0x86d1,
+    0x7fcd, 0x7f43, 0xa10b, 0x9e81, 0x9d15, 0xa289, 0xa279, 0x3e11, 0x3aa5, 0x3be3, 0x3daf, 0x3bff,
+    0xff8f, 0xff71, 0xfe03, 0xfe41, 0xfe05, 0xff2f, 0xfe7b, 0xfeb3, 0x0409, 0x0481, 0x1d7b, 0x1c4f,
+    0x1e6d, 0x1b7f, 0x1e71, 0xe875, 0xe2cd, 0xe351, 0xe363, 0xe329, 0x049d, 0x0427, 0xcbb3, 0x184d,
+    0x2ce1, 0x8861, 0x59b3, 0x2077, 0xff9d, 0xff2f,
+];
+pub const B8_PRIMES: [u64; 54] = [
+    0x2, 0x3, 0x5, 0x7, 0xb, 0xd, 0x11, 0x13, 0x17, 0x1d, 0x1f, 0x25, 0x29, 0x2b, 0x2f, 0x35, 0x3b,
+    0x3d, 0x43, 0x47, 0x49, 0x4f, 0x53, 0x59, 0x61, 0x65, 0x67, 0x6b, 0x6d, 0x71, 0x7f, 0x83, 0x89,
+    0x8b, 0x95, 0x97, 0x9d, 0xa3, 0xa7, 0xad, 0xb3, 0xb5, 0xbf, 0xc1, 0xc5, 0xc7, 0xd3, 0xdf, 0xe3,
+    0xe5, 0xe9, 0xef, 0xf1, 0xfb,
+];
+pub const MAGIC_PRIMES: [u64; 2] = [0x33, 0x65];
+pub const SINGLE_DIGIT_PRIMES: [u64; 3] = [0x3, 0x5, 0x7];
+pub const MASKS_ARRAY: [u64; 4] = [WORD_FFZZ, WORD_ZFFF, WORD_FFFZ, WORD_ZZFF];
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#misc
+pub const COMB_BIONOM: [(usize, usize); 6] = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+pub const COMB_BIONOM_SIZE: usize = 6;
+
+fn assert_words(factors: &Factors, operation: &str) {
+    for factor in factors.iter() {
+        assert!(
+            *factor <= MAX_UINT16,
+            "@{} left a factor wider than a word",
+            operation
+        );
+    }
+}
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-b-bitwise-operations
+pub fn octopad(input: &[u8]) -> Vec<u64> {
+    let mut padded: Vec<u64> = input.iter().map(|b| *b as u64).collect();
+    let original_len = padded.len() as u64;
+    let mut n = original_len;
+
+    while padded.len() as u64 % BLOCK_NUM != 0 {
+        let pad_bit = padded[(n % original_len) as usize] ^ (n & QWORD_14Z2F);
+        padded.push(pad_bit);
+        n = n + padded[(n % original_len) as usize];
+    }
+
+    padded
+}
+
+pub fn omega(num: u64) -> u64 {
+    (num & DWORD_4F4Z) >> WORD_WIDTH
+}
+
+pub fn epsilon(num: u64) -> u64 {
+    num & DWORD_4Z4F
+}
+
+pub fn lamed(num: u64, by: u64) -> u64 {
+    (num << by) | (num >> (WORD_WIDTH - by))
+}
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-c-bespoke-operations
+pub fn gorda(num: u64, by: u64) -> u64 {
+    let mut var = num;
+    var = lamed(var, by);
+    if var > MAX_UINT16 {
+        var = omega(var);
+    }
+    var & MAX_UINT16
+}
+
+pub fn tasu(a: u64, b: u64) -> u64 {
+    // ERRATUM: the result is assigned to `$var` but `$varc` is returned; both agree
+    // once cast to u16
+    let mut varc = a + b;
+    if varc > MAX_UINT16 {
+        varc = epsilon(varc);
+    }
+    varc & MAX_UINT16
+}
+
+pub fn centum(arr: &Factors, weights: &Factors) -> u64 {
+    let mut ctm = 0u64;
+    for i in 0..PORTION_NUM as usize {
+        ctm = ctm + arr[i] * weights[i];
+    }
+    ctm = ctm / PORTION_NUM;
+    if ctm > MAX_UINT16 {
+        ctm = omega(ctm);
+    }
+    ctm & MAX_UINT16
+}
+
+pub fn satem(arr: &Factors, weights: &Factors) -> u64 {
+    let mut stm = 0u64;
+    for i in 0..PORTION_NUM as usize {
+        stm = stm + arr[i] * weights[i];
+    }
+    stm = (stm + 1) / 2;
+    if stm > MAX_UINT16 {
+        stm = epsilon(stm);
+    }
+    stm & MAX_UINT16
+}
+
+pub fn tamaam(arr: &Factors) -> u64 {
+    let mut tmt = 0u64;
+    for i in 0..PORTION_NUM as usize {
+        tmt = tmt + arr[i];
+    }
+    tmt = tmt / PORTION_NUM;
+    if tmt > MAX_UINT16 {
+        tmt = omega(tmt);
+    }
+    tmt & MAX_UINT16
+}
+
+pub fn deca(arr: &Factors) -> u64 {
+    let mut dca = 0u64;
+    for i in 0..PORTION_NUM as usize {
+        dca = dca + arr[i];
+    }
+    dca = (dca + 1) / 2;
+    if dca > MAX_UINT16 {
+        dca = epsilon(dca);
+    }
+    dca & MAX_UINT16
+}
+
+pub fn get_8b_prime(num: u64) -> u64 {
+    B8_PRIMES[(num % B8_PRIME_NUM) as usize]
+}
+
+// SPEC.md calls this the natural logarithm; every port counts halvings down to one
+pub fn log2n(num: u64) -> u64 {
+    let mut n = num;
+    let mut halvings = 0;
+    while n > 1 {
+        n = n / 2;
+        halvings = halvings + 1;
+    }
+    halvings
+}
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-d-alphabet-operations
+pub fn alpha(tmp: &mut Factors) {
+    let aleph = (tmp[0] ^ tmp[1]) & WORD_ZZFF;
+    let daal = (tmp[2] ^ tmp[3]) & WORD_FFZZ;
+    let theh = (aleph | daal) % B8_PRIMES[0];
+    let gaaf = (aleph ^ daal) % B8_PRIMES[1];
+
+    tmp[0] = tmp[0] >> theh;
+    // ERRATUM: SPEC.md reads `($theh mod 2) add 1`
+    tmp[1] = tmp[1] >> (((theh + gaaf) % 2) + 1);
+    tmp[2] = tmp[2] >> gaaf;
+    assert_words(tmp, "ALPHA");
+}
+
+pub fn delta(tmp: &mut Factors) {
+    // ERRATUM: SPEC.md reads `*tmp[0] mod ^^WORD_FFFZ`
+    let mut alaf = (tmp[0] ^ WORD_FFFZ) % get_8b_prime(tmp[0]);
+    let mut dalat = (tmp[1] ^ WORD_FZZF) % get_8b_prime(tmp[1]);
+    let mut tit = (tmp[2] & WORD_ZFFF) % get_8b_prime(tmp[2]);
+    let mut gaman = (tmp[3] & WORD_FFZZ) % get_8b_prime(tmp[3]);
+
+    for _ in 0..PORTION_NUM {
+        alaf = alaf >> SINGLE_DIGIT_PRIMES[(dalat % SD_PRIME_NUM) as usize];
+        dalat = gorda(dalat, 2);
+        tit = tit >> SINGLE_DIGIT_PRIMES[(gaman % SD_PRIME_NUM) as usize];
+        gaman = gaman ^ ((alaf ^ WORD_ZZFF) >> SINGLE_DIGIT_PRIMES[(tit % SD_PRIME_NUM) as usize]);
+    }
+
+    tmp[1] = tmp[1] ^ (tmp[2] % MAGIC_PRIMES[(alaf % MAGIC_PRIME_NUM) as usize]);
+    tmp[2] = tmp[2] ^ (alaf + tit);
+    tmp[3] = tmp[3] ^ (tit + gaman);
+    assert_words(tmp, "DELTA");
+}
+
+pub fn theta(tmp: &mut Factors) {
+    let alef = tmp[0] % 2;
+    let dalet = tmp[1] % 2;
+    let tet = tmp[2] % 2;
+    let gimmel = tmp[3] % 2;
+
+    let ctm = centum(tmp, &[alef, dalet, tet, gimmel]);
+    let stm = satem(tmp, &[alef, dalet, tet, gimmel]);
+
+    tmp[0] = tmp[0] ^ (((ctm >> gimmel) ^ WORD_ZZFF) & WORD_ZZZF);
+    tmp[3] = tmp[3] ^ (((stm << alef) ^ WORD_FZFZ) & WORD_FZZZ);
+    assert_words(tmp, "THETA");
+}
+
+pub fn gamma(tmp: &mut Factors) {
+    let min = *tmp.iter().min().unwrap();
+    let argmin = tmp.iter().position(|f| *f == min).unwrap() as u64;
+    let max = *tmp.iter().max().unwrap();
+    let argmax = tmp.iter().position(|f| *f == max).unwrap() as u64;
+
+    let ay = (argmin & NIBBLET_01) as usize;
+    let dee = (argmax ^ NIBBLET_10) as usize;
+    let thorn = (argmin & NIBBLET_11) as usize;
+    let gee = (argmax ^ NIBBLET_00) as usize;
+
+    let alaph = tmp[ay] % get_8b_prime(tmp[thorn]);
+    let dalath = (get_8b_prime(max) ^ WORD_ZFZF) % get_8b_prime(min);
+    let teth = max % get_8b_prime(max);
+    let gamal = tmp[dee] % get_8b_prime((min + max) / 2);
+
+    // ERRATUM: SPEC.md reads `xhr` for the first shift
+    tmp[ay] = tmp[ay] >> ((alaph ^ WORD_ZZFZ) % WORD_WIDTH);
+    tmp[dee] = tmp[dee] >> ((gamal ^ WORD_FZZZ) % ((max % 2) + 1));
+    tmp[thorn] = tmp[thorn] ^ (log2n(dalath) & WORD_ZFFF);
+    tmp[gee] = tmp[gee] ^ (log2n(teth) >> ((gamal % 2) + 1));
+    assert_words(tmp, "GAMMA");
+}
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-e-round-methods
+pub fn apply_alphabet(tmp: &mut Factors) {
+    alpha(tmp);
+    delta(tmp);
+    theta(tmp);
+    gamma(tmp);
+}
+
+pub fn apply_bahman(tmp: &mut Factors, curr_prime: u64) {
+    let cica = (curr_prime % PORTION_NUM) as usize;
+    let mica = (cica + 1) % PORTION_NUM as usize;
+    let nica = (mica + 2) % PORTION_NUM as usize;
+    let wica = (nica + 3) % PORTION_NUM as usize;
+
+    let mianju = (tmp[cica] % MASKS_NUM) as usize;
+    let mianja = (tmp[mica] % MASKS_NUM) as usize;
+
+    let sosu = (tmp[nica] % ROUND_PRIME_NUM) as usize;
+    let sosa = (tmp[wica] % ROUND_PRIME_NUM) as usize;
+
+    tmp[cica] = tmp[cica] ^ ((tmp[mica] << cica) & MASKS_ARRAY[mianju]);
+    tmp[wica] = tmp[wica] & (tmp[wica] ^ ROUND_PRIMES[sosu]);
+    tmp[nica] = tmp[nica] ^ ((tmp[cica] << (wica * 2)) & MASKS_ARRAY[mianja]);
+    tmp[mica] = tmp[mica] | (tmp[nica] | ROUND_PRIMES[sosa]);
+    assert_words(tmp, "APPLY_BAHMAN");
+}
+
+pub fn apply_round_prime(tmp: &mut Factors) {
+    for i in 0..ROUND_PRIME_NUM as usize {
+        tmp[0] = tmp[0] % ROUND_PRIMES[i];
+        tmp[1] = tmp[1] % ROUND_PRIMES[i];
+        tmp[2] = tmp[2] % ROUND_PRIMES[i];
+        tmp[3] = tmp[3] % ROUND_PRIMES[i];
+        apply_bahman(tmp, ROUND_PRIMES[i]);
+    }
+}
+
+pub fn apply_shuffle(tmp: &mut Factors) {
+    for i in 0..COMB_BIONOM_SIZE {
+        let (iof, iwith) = COMB_BIONOM[i];
+        tmp.swap(iof, iwith);
+    }
+}
+
+pub fn apply_add_temporary(base: &mut Factors, tmp: &Factors) {
+    for i in 0..PORTION_NUM as usize {
+        base[i] = tasu(base[i], tmp[i]);
+    }
+}
+
+pub fn apply_round(base: &mut Factors) {
+    let mut tmp = *base;
+    apply_alphabet(&mut tmp);
+    apply_round_prime(&mut tmp);
+    apply_shuffle(&mut tmp);
+    apply_add_temporary(base, &tmp);
+}
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#part-f-block-methods
+pub fn apply_bytes_to_factors(base: &mut Factors, portion: &Factors, index: u64) {
+    let tmt = tamaam(portion);
+    let dca = deca(portion);
+    let tmt_odd_factor = MAX_UINT16 ^ (tmt % (dca + 2));
+    let dca_odd_factor = MAX_UINT16 ^ (dca % (tmt + 3));
+
+    let ng = ((portion[0] + index) % PORTION_NUM) as usize;
+    let chu = ((portion[1] + index) % PORTION_NUM) as usize;
+    let yo = ((portion[2] + index) % PORTION_NUM) as usize;
+    let eo = ((portion[3] + index) % PORTION_NUM) as usize;
+
+    let zam = portion[0] % B8_PRIMES[(portion[chu] % B8_PRIME_NUM) as usize];
+    let pez = portion[1] % B8_PRIMES[(portion[yo] % B8_PRIME_NUM) as usize];
+    let dit = portion[2] % B8_PRIMES[(portion[eo] % B8_PRIME_NUM) as usize];
+    let kit = portion[3] % B8_PRIMES[(portion[ng] % B8_PRIME_NUM) as usize];
+
+    // The trailing `or $zam` / `or $dit` bind inside the parentheses in every port
+    base[ng] = base[ng] ^ ((((portion[eo] >> chu) | tmt) ^ dca_odd_factor) | zam);
+    base[chu] = base[chu] ^ (((portion[yo] & dca) ^ tmt_odd_factor) ^ pez);
+    base[yo] = base[yo] ^ (((portion[chu] ^ tmt) ^ dca_odd_factor) | dit);
+    base[eo] = base[eo] ^ ((((portion[ng] >> yo) | dca) ^ tmt_odd_factor) ^ kit);
+
+    base[0] = base[0] >> (portion[3] % (ng as u64 + 1));
+    base[1] = base[1] >> (portion[2] % (chu as u64 + 1));
+    base[2] = base[2] ^ (portion[1] >> (dca % 2));
+    // ERRATUM: SPEC.md reads `*base*[3] assign-with *base*[0] shr ...`
+    base[3] = base[3] >> (portion[0] % (eo as u64 + 1));
+    assert_words(base, "APPLY_BYTES_TO_FACTORS");
+}
+
+pub fn process_block(base: &mut Factors, block: &[u64]) {
+    for i in (0..BLOCK_NUM as usize).step_by(CHUNK_NUM as usize) {
+        for j in (i..i + CHUNK_NUM as usize).step_by(PORTION_NUM as usize) {
+            let portion = [block[j], block[j + 1], block[j + 2], block[j + 3]];
+            for m in 0..ROUND_NUM {
+                // ERRATUM: SPEC.md omits the round index `m` from the call
+                apply_bytes_to_factors(base, &portion, m);
+                apply_round(base);
+            }
+        }
+    }
+}
+
+// https://github.com/Chubek/PoxHash/blob/master/SPEC.md#the-main-function
+pub fn pox_hash(message: &[u8]) -> Factors {
+    let message_words_padded = octopad(message);
+    let mut factor_array = [PRIME_INIT_A, PRIME_INIT_B, PRIME_INIT_C, PRIME_INIT_D];
+
+    for i in (0..message_words_padded.len()).step_by(BLOCK_NUM as usize) {
+        let block = &message_words_padded[i..i + BLOCK_NUM as usize];
+        process_block(&mut factor_array, block);
+    }
+
+    factor_array
+}
+
+// Not in SPEC.md: the word mode behind `pox_hash_words`, as USAGE.md describes it
+pub const WORDS_PAD_MARKER: u64 = 0x8000;
+
+pub fn wordpad(input: &[u64]) -> Vec<u64> {
+    let mut padded = input.to_vec();
+    padded.push(WORDS_PAD_MARKER);
+    while padded.len() as u64 % BLOCK_NUM != 0 {
+        padded.push(0);
+    }
+    padded
+}
+
+pub fn pox_hash_words(message: &[u64]) -> Factors {
+    let message_words_padded = wordpad(message);
+    let mut factor_array = [
+        PRIME_INIT_A ^ MAX_UINT16,
+        PRIME_INIT_B ^ MAX_UINT16,
+        PRIME_INIT_C ^ MAX_UINT16,
+        PRIME_INIT_D ^ MAX_UINT16,
+    ];
+
+    for i in (0..message_words_padded.len()).step_by(BLOCK_NUM as usize) {
+        let block = &message_words_padded[i..i + BLOCK_NUM as usize];
+        process_block(&mut factor_array, block);
+    }
+
+    factor_array
+}
+
+// Not in SPEC.md either: PoxHash-MD, as USAGE.md describes it for `pox_hash_md`
+pub const MD_PAD_MARKER: u64 = 0x80;
+pub const MD_LENGTH_OFFSET: u64 = 56;
+
+pub fn mdpad(input: &[u8]) -> Vec<u64> {
+    let mut padded: Vec<u64> = input.iter().map(|b| *b as u64).collect();
+    padded.push(MD_PAD_MARKER);
+    while padded.len() as u64 % BLOCK_NUM != MD_LENGTH_OFFSET {
+        padded.push(0);
+    }
+    let length = input.len() as u64;
+    for i in 0..8 {
+        padded.push((length >> (8 * i)) & WORD_ZZFF);
+    }
+    padded
+}
+
+pub fn pox_hash_md(message: &[u8]) -> Factors {
+    let message_words_padded = mdpad(message);
+    let swap = |prime: u64| ((prime & WORD_ZZFF) << 8) | ((prime & WORD_FFZZ) >> 8);
+    let mut factor_array = [
+        swap(PRIME_INIT_A),
+        swap(PRIME_INIT_B),
+        swap(PRIME_INIT_C),
+        swap(PRIME_INIT_D),
+    ];
+
+    for i in (0..message_words_padded.len()).step_by(BLOCK_NUM as usize) {
+        let block = &message_words_padded[i..i + BLOCK_NUM as usize];
+        process_block(&mut factor_array, block);
+    }
+
+    factor_array
+}
@@ -0,0 +1,179 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                               Text Encoding Tests                               //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{hash_text, pox_hash, Charset, TextEncoding, TextEncodingError, Unmappable};
+
+// Hexdigests from the Python port, hashing `str.encode` with the matching codec
+const KNOWN_TEXTS: &[(&str, &str, &str, &str)] = &[
+    (
+        "PoxHash",
+        "07D04B8CD2E47BF3",
+        "7E5426ABC1856121",
+        "7584BED7D1BA833C",
+    ),
+    (
+        "naïve café",
+        "1D5809892526C061",
+        "089F24EFB8F61FF2",
+        "0C1934F7202834A3",
+    ),
+    (
+        "日本語 🦀",
+        "B2CCDC4303AA26D9",
+        "00D3B2CCC26BC681",
+        "09940DD72602FDBA",
+    ),
+    (
+        "line1\r\nline2\r\n",
+        "45DED351DEA884D1",
+        "1BEB09031BFEE921",
+        "DAE05F22488533D7",
+    ),
+];
+
+#[test]
+fn known_texts_hash_as_in_python() {
+    for (text, utf8, utf16le, utf16be) in KNOWN_TEXTS {
+        assert_eq!(
+            hash_text(text, TextEncoding::UTF8).unwrap().hexdigest,
+            *utf8
+        );
+        assert_eq!(
+            hash_text(text, TextEncoding::UTF16LE).unwrap().hexdigest,
+            *utf16le
+        );
+        assert_eq!(
+            hash_text(text, TextEncoding::UTF16BE).unwrap().hexdigest,
+            *utf16be
+        );
+    }
+    assert_eq!(
+        hash_text("naïve café", TextEncoding::LATIN1)
+            .unwrap()
+            .hexdigest,
+        "FF664D700755BB3E"
+    );
+}
+
+#[test]
+fn utf8_matches_the_string_bytes() {
+    let text = "日本語 🦀 naïve";
+    assert_eq!(
+        hash_text(text, TextEncoding::UTF8).unwrap().hexdigest,
+        pox_hash(&text.as_bytes().to_vec()).hexdigest
+    );
+}
+
+#[test]
+fn utf16_writes_code_units_without_a_bom() {
+    // U+1F980 is the surrogate pair D83E DD80, as in a JavaScript string
+    assert_eq!(
+        TextEncoding::UTF16LE.encode("a🦀").unwrap(),
+        vec![0x61, 0x00, 0x3e, 0xd8, 0x80, 0xdd]
+    );
+    assert_eq!(
+        TextEncoding::UTF16BE.encode("a🦀").unwrap(),
+        vec![0x00, 0x61, 0xd8, 0x3e, 0xdd, 0x80]
+    );
+    assert_eq!(TextEncoding::UTF16LE.encode("").unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn latin1_handles_unmappable_characters() {
+    let error = TextEncoding::LATIN1.encode("ÿ€").unwrap_err();
+    assert_eq!(
+        error,
+        TextEncodingError::Unmappable {
+            position: 1,
+            found: '€'
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "`€` (U+20AC) at position 1 has no Latin-1 byte"
+    );
+    assert_eq!(
+        hash_text("日本語 🦀", TextEncoding::LATIN1).err(),
+        Some(TextEncodingError::Unmappable {
+            position: 0,
+            found: '日'
+        })
+    );
+    let replacing = TextEncoding::LATIN1.unmappable(Unmappable::Replace(b'?'));
+    assert_eq!(replacing.encode("ÿ€").unwrap(), vec![0xff, b'?']);
+    assert_eq!(
+        hash_text("日本語 🦀", replacing).unwrap().hexdigest,
+        "052E308551AF4EE3"
+    );
+    // Characters outside Latin-1 map in every other charset
+    let other = TextEncoding::UTF8.unmappable(Unmappable::Reject);
+    assert!(other.encode("日本語 🦀").is_ok());
+}
+
+#[test]
+fn latin1_positions_count_the_text_before_newlines_are_folded() {
+    let normalizing = TextEncoding::LATIN1.normalize_newlines();
+    assert_eq!(
+        normalizing.encode("a\r\nb€"),
+        Err(TextEncodingError::Unmappable {
+            position: 4,
+            found: '€'
+        })
+    );
+    assert_eq!(
+        normalizing
+            .unmappable(Unmappable::Replace(b'?'))
+            .encode("a\r\nb€\r")
+            .unwrap(),
+        b"a\nb?\r".to_vec()
+    );
+}
+
+#[test]
+fn newline_normalization_folds_crlf_only() {
+    let normalizing = TextEncoding::UTF8.normalize_newlines();
+    assert_eq!(
+        normalizing.encode("a\r\nb\rc\n\r\n").unwrap(),
+        b"a\nb\rc\n\n".to_vec()
+    );
+    assert_eq!(
+        hash_text("line1\r\nline2\r\n", normalizing)
+            .unwrap()
+            .hexdigest,
+        "129E1F4399D1975B"
+    );
+    assert_eq!(
+        hash_text(
+            "line1\r\nline2\r\n",
+            TextEncoding::new(Charset::Utf16Le).normalize_newlines()
+        )
+        .unwrap()
+        .hexdigest,
+        "20C59D8E75C5A2B9"
+    );
+}
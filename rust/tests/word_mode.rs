@@ -0,0 +1,144 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                                 Word Mode Tests                                 //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{hash_text, pox_hash, pox_hash_words, Multihash, MultihashError, TextEncoding};
+
+#[allow(dead_code)]
+#[path = "../splitmix.rs"]
+mod splitmix;
+use splitmix::SplitMix64;
+
+// Spot checks from vectors/poxhash-words-vectors-v1.json
+const KNOWN_WORDS: &[(&[u16], &str)] = &[
+    (&[], "33286C34FD30EDAF"),
+    (&[0x0000], "CAAADE7BA8F23676"),
+    (&[0x00ff], "6EC4579767FDB464"),
+    (&[0x8000], "0568EDBBCFF0E64C"),
+    (&[0xffff], "7766C00F507F67B7"),
+    (&[0xffff; 64], "5EE0E0745884D6C3"),
+];
+
+#[test]
+fn known_words_hash_as_in_the_vectors() {
+    for (words, hexdigest) in KNOWN_WORDS {
+        assert_eq!(
+            pox_hash_words(words).hexdigest,
+            *hexdigest,
+            "{:04x?}",
+            words
+        );
+    }
+}
+
+#[test]
+fn sampled_word_messages_differ_from_the_same_bytes() {
+    let mut rng = SplitMix64 { state: 0x37 };
+    assert_ne!(pox_hash_words(&[]).words, pox_hash(&Vec::new()).words);
+    for len in 1..200 {
+        let bytes = (0..len).map(|_| rng.next_u64() as u8).collect::<Vec<u8>>();
+        let words = bytes.iter().map(|b| *b as u16).collect::<Vec<u16>>();
+        assert_ne!(pox_hash_words(&words).words, pox_hash(&bytes).words);
+    }
+    let utf16 = hash_text("PoxHash", TextEncoding::UTF16LE).unwrap();
+    let units = "PoxHash".encode_utf16().collect::<Vec<u16>>();
+    assert_ne!(pox_hash_words(&units).words, utf16.words);
+}
+
+#[test]
+fn padding_keeps_trailing_words_apart() {
+    let messages: &[&[u16]] = &[
+        &[],
+        &[0x8000],
+        &[0x0000],
+        &[0x8000, 0x0000],
+        &[0x0001],
+        &[0x0001, 0x0000],
+        &[0x0001, 0x8000],
+        &[0x0000; 63],
+        &[0x0000; 64],
+    ];
+    for (i, a) in messages.iter().enumerate() {
+        for b in &messages[i + 1..] {
+            assert_ne!(
+                pox_hash_words(a).words,
+                pox_hash_words(b).words,
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn single_words_hash_differently() {
+    let mut rng = SplitMix64 { state: 0x10000 };
+    let mut digests = (0..0x100u32)
+        .chain(0xff00..=0xffff)
+        .chain((0..512).map(|_| rng.next_u64() as u32 & 0xffff))
+        .map(|w| (w, pox_hash_words(&[w as u16]).quad))
+        .collect::<Vec<(u32, u64)>>();
+    digests.sort_unstable();
+    digests.dedup();
+    let mut quads = digests.iter().map(|d| d.1).collect::<Vec<u64>>();
+    quads.sort_unstable();
+    quads.dedup();
+    assert_eq!(quads.len(), digests.len());
+}
+
+#[test]
+#[ignore]
+fn soak_every_single_word_hashes_differently() {
+    let mut digests = (0..=0xffffu32)
+        .map(|w| pox_hash_words(&[w as u16]).quad)
+        .collect::<Vec<u64>>();
+    digests.sort_unstable();
+    digests.dedup();
+    assert_eq!(digests.len(), 0x10000);
+}
+
+#[test]
+fn word_digests_have_their_own_multihash_code() {
+    let digest = pox_hash_words(&[0xffff]);
+    let multihash = Multihash::from_words_digest(&digest);
+    assert_eq!(multihash.code(), 0x300a10);
+    assert_eq!(multihash.name(), "poxhash-words-64");
+    assert_ne!(multihash, digest.to_multihash());
+    let decoded = Multihash::from_bytes(&multihash.to_bytes()).unwrap();
+    assert_eq!(
+        decoded.to_words_digest().unwrap().hexdigest,
+        digest.hexdigest
+    );
+    assert_eq!(
+        decoded.to_pox_digest().err(),
+        Some(MultihashError::UnexpectedCode {
+            expected: 0x300a00,
+            found: 0x300a10
+        })
+    );
+}
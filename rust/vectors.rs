@@ -0,0 +1,725 @@
+/////////////////////////////////////////////////////////////////////////////////////
+//                 Test-vector generator for libpoxh.rs (poxh-vectors)             //
+//                          March 2023 - Chubak Bidpaa                             //
+/////////////////////////////////////////////////////////////////////////////////////
+// MIT License                                                                     //
+//                                                                                 //
+// Copyright (c) 2023 Chubak Bidpaa                                                //
+//                                                                                 //
+// Permission is hereby granted, free of charge, to any person obtaining a copy    //
+// of this software and associated documentation files (the "Software"), to deal   //
+// in the Software without restriction, including without limitation the rights    //
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell       //
+// copies of the Software, and to permit persons to whom the Software is           //
+// furnished to do so, subject to the following conditions:                        //
+//                                                                                 //
+// The above copyright notice and this permission notice shall be included in all  //
+// copies or substantial portions of the Software.                                 //
+//                                                                                 //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR      //
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,        //
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE     //
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER          //
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,   //
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE   //
+// SOFTWARE.                                                                       //
+/////////////////////////////////////////////////////////////////////////////////////
+
+extern crate libpoxh;
+use libpoxh::{pox_hash, pox_hash_md, pox_hash_words, PoxDigest};
+
+#[allow(dead_code)]
+mod splitmix;
+use splitmix::SplitMix64;
+
+const VECTORS_FORMAT: &'static str = "poxhash-test-vectors";
+const WORDS_VECTORS_FORMAT: &'static str = "poxhash-words-test-vectors";
+const MD_VECTORS_FORMAT: &'static str = "poxhash-md-test-vectors";
+const VECTORS_VERSION: u64 = 1;
+const VECTORS_SPEC: &'static str = "PoxHash v2";
+const DEFAULT_OUTPUT: &'static str = "poxhash-vectors-v1.json";
+const DEFAULT_WORDS_OUTPUT: &'static str = "poxhash-words-vectors-v1.json";
+const DEFAULT_MD_OUTPUT: &'static str = "poxhash-md-vectors-v1.json";
+
+const FLAG_CHECK: &'static str = "--check";
+const FLAG_OUTPUT: &'static str = "-o";
+const FLAG_HELP: &'static str = "-h";
+const FLAG_WORDS: &'static str = "--words";
+const FLAG_MD: &'static str = "--md";
+
+const BLOCK_NUM: usize = 64;
+const EDGE_DELTAS: &'static [isize] = &[-2, -1, 0, 1, 2];
+const EDGE_BLOCKS: &'static [usize] = &[1, 2, 3, 4, 8, 16];
+const REPEAT_BYTES: &'static [u8] = &[0x00, 0x01, 0x20, 0x61, 0x7f, 0x80, 0xfe, 0xff];
+const REPEAT_LENGTHS: &'static [usize] = &[1, 2, 63, 64, 65, 127, 128, 129, 1000];
+const RANDOM_SEED_NUM: u64 = 64;
+const RANDOM_MAX_LEN: u64 = 2048;
+const EDGE_SEED: u64 = 0x506f7848617368;
+
+const WORDS_EDGE_BLOCKS: &'static [usize] = &[1, 2, 3, 4];
+const WORDS_EDGE_SEED: u64 = 0x576f726473;
+const REPEAT_WORDS: &'static [u16] = &[0x0000, 0x0001, 0x00ff, 0x0100, 0x7fff, 0x8000, 0xffff];
+const WORDS_REPEAT_LENGTHS: &'static [usize] = &[1, 2, 63, 64, 65, 128];
+const SINGLE_WORDS: &'static [u16] = &[
+    0x0000, 0x0001, 0x007f, 0x0080, 0x00ff, 0x0100, 0x7fff, 0x8000, 0xfffe, 0xffff,
+];
+const WORDS_RANDOM_SEED_NUM: u64 = 32;
+const WORDS_RANDOM_MAX_LEN: u64 = 1024;
+
+const MD_EDGE_LENGTHS: &'static [usize] = &[54, 55, 56, 57, 118, 119, 120, 121];
+const MD_EDGE_SEED: u64 = 0x4d44;
+
+const DIGEST_FIELDS: &'static [&'static str] = &[
+    "sexdigest",
+    "vigdigest",
+    "hexdigest",
+    "tetdigest",
+    "duodigest",
+    "octdigest",
+    "sendigest",
+    "bindigest",
+];
+
+macro_rules! error_out {
+    ($($arg: tt)*) => {{
+        eprint!("\x1b[1;31mError:\x1b[0m ");
+        eprintln!($($arg)*);
+        std::process::exit(2);
+    }};
+}
+
+impl SplitMix64 {
+    fn fill(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| (self.next_u64() >> 56) as u8).collect()
+    }
+
+    fn fill_words(&mut self, len: usize) -> Vec<u16> {
+        (0..len).map(|_| (self.next_u64() >> 48) as u16).collect()
+    }
+}
+
+// Byte files hold `pox_hash` vectors, word files hold `pox_hash_words` vectors and MD
+// files hold `pox_hash_md` vectors. Each has its own `format` string so that none of them
+// can be checked as another.
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Bytes,
+    Words,
+    Md,
+}
+
+impl Mode {
+    fn from_format(format: &str) -> Option<Mode> {
+        match format {
+            VECTORS_FORMAT => Some(Mode::Bytes),
+            WORDS_VECTORS_FORMAT => Some(Mode::Words),
+            MD_VECTORS_FORMAT => Some(Mode::Md),
+            _ => None,
+        }
+    }
+
+    fn format(&self) -> &'static str {
+        match self {
+            Mode::Bytes => VECTORS_FORMAT,
+            Mode::Words => WORDS_VECTORS_FORMAT,
+            Mode::Md => MD_VECTORS_FORMAT,
+        }
+    }
+
+    fn default_output(&self) -> &'static str {
+        match self {
+            Mode::Bytes => DEFAULT_OUTPUT,
+            Mode::Words => DEFAULT_WORDS_OUTPUT,
+            Mode::Md => DEFAULT_MD_OUTPUT,
+        }
+    }
+
+    fn generate_corpus(&self) -> Vec<Vector> {
+        match self {
+            Mode::Bytes => generate_corpus(),
+            Mode::Words => generate_words_corpus(),
+            Mode::Md => generate_md_corpus(),
+        }
+    }
+
+    fn hash(&self, message: &Message) -> PoxDigest {
+        match (self, message) {
+            (Mode::Md, Message::Bytes(bytes)) => pox_hash_md(bytes).into_digest(),
+            (_, Message::Bytes(bytes)) => pox_hash(bytes),
+            (_, Message::Words(words)) => pox_hash_words(words),
+        }
+    }
+}
+
+enum Message {
+    Bytes(Vec<u8>),
+    Words(Vec<u16>),
+}
+
+impl Message {
+    fn len(&self) -> usize {
+        match self {
+            Message::Bytes(bytes) => bytes.len(),
+            Message::Words(words) => words.len(),
+        }
+    }
+
+    // Bytes are written as one lowercase hex string, words as an array of integers
+    fn to_json(&self) -> String {
+        match self {
+            Message::Bytes(bytes) => format!("\"{}\"", to_hex(bytes)),
+            Message::Words(words) => format!(
+                "[{}]",
+                words
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    fn from_json(mode: Mode, json: &Json) -> Option<Message> {
+        match mode {
+            Mode::Bytes | Mode::Md => json.as_str().and_then(from_hex).map(Message::Bytes),
+            Mode::Words => json
+                .as_array()?
+                .iter()
+                .map(|w| w.as_u64().filter(|w| *w <= 0xffff).map(|w| w as u16))
+                .collect::<Option<Vec<u16>>>()
+                .map(Message::Words),
+        }
+    }
+}
+
+struct Vector {
+    name: String,
+    message: Message,
+}
+
+fn generate_corpus() -> Vec<Vector> {
+    let mut corpus = vec![Vector {
+        name: String::from("empty"),
+        message: Message::Bytes(Vec::new()),
+    }];
+
+    for blocks in EDGE_BLOCKS {
+        for delta in EDGE_DELTAS {
+            let len = (blocks * BLOCK_NUM) as isize + delta;
+            let mut prng = SplitMix64::new(EDGE_SEED ^ len as u64);
+            corpus.push(Vector {
+                name: format!("edge-len-{}", len),
+                message: Message::Bytes(prng.fill(len as usize)),
+            });
+        }
+    }
+
+    for byte in REPEAT_BYTES {
+        for len in REPEAT_LENGTHS {
+            corpus.push(Vector {
+                name: format!("repeat-{:02x}-len-{}", byte, len),
+                message: Message::Bytes(vec![*byte; *len]),
+            });
+        }
+    }
+
+    for byte in 0..=255u8 {
+        corpus.push(Vector {
+            name: format!("single-{:02x}", byte),
+            message: Message::Bytes(vec![byte]),
+        });
+    }
+
+    for seed in 1..=RANDOM_SEED_NUM {
+        let mut prng = SplitMix64::new(seed);
+        let len = 1 + (prng.next_u64() % RANDOM_MAX_LEN) as usize;
+        corpus.push(Vector {
+            name: format!("random-seed-{}", seed),
+            message: Message::Bytes(prng.fill(len)),
+        });
+    }
+
+    corpus
+}
+
+// The byte corpus plus lengths around 56 mod 64, where the length no longer fits in the
+// last message block
+fn generate_md_corpus() -> Vec<Vector> {
+    let mut corpus = generate_corpus();
+    for len in MD_EDGE_LENGTHS {
+        let mut prng = SplitMix64::new(MD_EDGE_SEED ^ *len as u64);
+        corpus.push(Vector {
+            name: format!("md-edge-len-{}", len),
+            message: Message::Bytes(prng.fill(*len)),
+        });
+    }
+    corpus
+}
+
+fn generate_words_corpus() -> Vec<Vector> {
+    let mut corpus = vec![Vector {
+        name: String::from("empty"),
+        message: Message::Words(Vec::new()),
+    }];
+
+    for blocks in WORDS_EDGE_BLOCKS {
+        for delta in EDGE_DELTAS {
+            let len = (blocks * BLOCK_NUM) as isize + delta;
+            let mut prng = SplitMix64::new(WORDS_EDGE_SEED ^ len as u64);
+            corpus.push(Vector {
+                name: format!("edge-len-{}", len),
+                message: Message::Words(prng.fill_words(len as usize)),
+            });
+        }
+    }
+
+    for word in REPEAT_WORDS {
+        for len in WORDS_REPEAT_LENGTHS {
+            corpus.push(Vector {
+                name: format!("repeat-{:04x}-len-{}", word, len),
+                message: Message::Words(vec![*word; *len]),
+            });
+        }
+    }
+
+    for word in SINGLE_WORDS {
+        corpus.push(Vector {
+            name: format!("single-{:04x}", word),
+            message: Message::Words(vec![*word]),
+        });
+    }
+
+    for seed in 1..=WORDS_RANDOM_SEED_NUM {
+        let mut prng = SplitMix64::new(WORDS_EDGE_SEED ^ seed);
+        let len = 1 + (prng.next_u64() % WORDS_RANDOM_MAX_LEN) as usize;
+        corpus.push(Vector {
+            name: format!("random-seed-{}", seed),
+            message: Message::Words(prng.fill_words(len)),
+        });
+    }
+
+    corpus
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn digest_field<'a>(digest: &'a PoxDigest, field: &str) -> &'a str {
+    match field {
+        "sexdigest" => &digest.sexdigest,
+        "vigdigest" => &digest.vigdigest,
+        "hexdigest" => &digest.hexdigest,
+        "tetdigest" => &digest.tetdigest,
+        "duodigest" => &digest.duodigest,
+        "octdigest" => &digest.octdigest,
+        "sendigest" => &digest.sendigest,
+        "bindigest" => &digest.bindigest,
+        _ => unreachable!(),
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn render_vectors(mode: Mode, corpus: &[Vector]) -> String {
+    let mut out = String::new();
+    out.push_str("{\n");
+    out.push_str(&format!("  \"format\": \"{}\",\n", mode.format()));
+    out.push_str(&format!("  \"version\": {},\n", VECTORS_VERSION));
+    out.push_str(&format!("  \"spec\": \"{}\",\n", VECTORS_SPEC));
+    out.push_str(&format!("  \"count\": {},\n", corpus.len()));
+    out.push_str("  \"vectors\": [\n");
+    for (i, vector) in corpus.iter().enumerate() {
+        let digest = mode.hash(&vector.message);
+        out.push_str("    {\n");
+        out.push_str(&format!("      \"name\": \"{}\",\n", escape_json(&vector.name)));
+        out.push_str(&format!("      \"length\": {},\n", vector.message.len()));
+        out.push_str(&format!("      \"message\": {},\n", vector.message.to_json()));
+        out.push_str(&format!(
+            "      \"words\": [{}, {}, {}, {}],\n",
+            digest.words[0], digest.words[1], digest.words[2], digest.words[3]
+        ));
+        for (j, field) in DIGEST_FIELDS.iter().enumerate() {
+            let separator = if j + 1 == DIGEST_FIELDS.len() { "" } else { "," };
+            out.push_str(&format!(
+                "      \"{}\": \"{}\"{}\n",
+                field,
+                escape_json(digest_field(&digest, field)),
+                separator
+            ));
+        }
+        out.push_str(if i + 1 == corpus.len() { "    }\n" } else { "    },\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+enum Json {
+    Literal,
+    Number(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_u64(&self) -> Option<u64> {
+        match self {
+            Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u64),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn parse(src: &'a str) -> Result<Json, String> {
+        let mut parser = JsonParser {
+            src: src.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.src.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, what: &str) -> String {
+        format!("{} at byte {}", what, self.pos)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.src.len() && (self.src[self.pos] as char).is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.src.get(self.pos) != Some(&byte) {
+            return Err(self.error(&format!("expected `{}`", byte as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    // The vector format has no booleans or nulls, so their values are not kept
+    fn literal(&mut self, word: &str) -> Result<Json, String> {
+        if self.src[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(Json::Literal)
+        } else {
+            Err(self.error("unknown literal"))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.src.get(self.pos) {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => Ok(Json::Str(self.string()?)),
+            Some(b't') => self.literal("true"),
+            Some(b'f') => self.literal("false"),
+            Some(b'n') => self.literal("null"),
+            Some(_) => self.number(),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.src.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(b':')?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.src.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.src.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.src.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.src.get(self.pos) != Some(&b'"') {
+            return Err(self.error("expected a string"));
+        }
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.src.get(self.pos) {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    let escaped = match self.src.get(self.pos + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'u') => {
+                            let hex = self
+                                .src
+                                .get(self.pos + 2..self.pos + 6)
+                                .and_then(|h| std::str::from_utf8(h).ok())
+                                .and_then(|h| u32::from_str_radix(h, 16).ok())
+                                .and_then(std::char::from_u32)
+                                .ok_or_else(|| self.error("bad unicode escape"))?;
+                            self.pos += 4;
+                            hex
+                        }
+                        _ => return Err(self.error("bad escape")),
+                    };
+                    let mut buf = [0u8; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                    self.pos += 2;
+                }
+                Some(b) => {
+                    bytes.push(*b);
+                    self.pos += 1;
+                }
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        while self.pos < self.src.len() && b"+-0123456789.eE".contains(&self.src[self.pos]) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.src[start..self.pos])
+            .ok()
+            .and_then(|n| n.parse::<f64>().ok())
+            .map(Json::Number)
+            .ok_or_else(|| self.error("bad number"))
+    }
+}
+
+fn check_vector(mode: Mode, vector: &Json) -> Result<(), String> {
+    let name = vector
+        .get("name")
+        .and_then(Json::as_str)
+        .ok_or("vector without a name")?;
+    let message = vector
+        .get("message")
+        .and_then(|m| Message::from_json(mode, m))
+        .ok_or_else(|| format!("{}: bad or missing `message`", name))?;
+    let length = vector.get("length").and_then(Json::as_u64);
+    if length != Some(message.len() as u64) {
+        return Err(format!("{}: `length` does not match `message`", name));
+    }
+
+    let digest = mode.hash(&message);
+    let mut mismatches = Vec::new();
+    let words = vector
+        .get("words")
+        .and_then(Json::as_array)
+        .map(|w| w.iter().filter_map(Json::as_u64).collect::<Vec<u64>>())
+        .unwrap_or_default();
+    let actual_words = digest.words.iter().map(|w| *w as u64).collect::<Vec<u64>>();
+    if words != actual_words {
+        mismatches.push(format!("words: expected {:?}, got {:?}", words, actual_words));
+    }
+    for field in DIGEST_FIELDS {
+        let expected = vector.get(field).and_then(Json::as_str).unwrap_or("");
+        let actual = digest_field(&digest, field);
+        if expected != actual {
+            mismatches.push(format!("{}: expected {}, got {}", field, expected, actual));
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{}:\n\t{}", name, mismatches.join("\n\t")))
+    }
+}
+
+fn check_vectors(path: &str) {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => error_out!("cannot read `{}`: {}", path, e),
+    };
+    let root = match JsonParser::parse(&contents) {
+        Ok(root) => root,
+        Err(e) => error_out!("`{}` is not valid JSON: {}", path, e),
+    };
+
+    let mode = match root
+        .get("format")
+        .and_then(Json::as_str)
+        .and_then(Mode::from_format)
+    {
+        Some(mode) => mode,
+        None => error_out!(
+            "`{}` is not a {} or {} file",
+            path,
+            VECTORS_FORMAT,
+            WORDS_VECTORS_FORMAT
+        ),
+    };
+    match root.get("version").and_then(Json::as_u64) {
+        Some(VECTORS_VERSION) => {}
+        Some(v) => error_out!("unsupported vector file version {}", v),
+        None => error_out!("vector file has no version"),
+    }
+    let vectors = match root.get("vectors").and_then(Json::as_array) {
+        Some(vectors) => vectors,
+        None => error_out!("vector file has no `vectors` array"),
+    };
+    if root.get("count").and_then(Json::as_u64) != Some(vectors.len() as u64) {
+        error_out!("`count` does not match the number of vectors");
+    }
+
+    let mut failed = 0usize;
+    for vector in vectors {
+        if let Err(e) = check_vector(mode, vector) {
+            println!("\x1b[1;31mFAILED\x1b[0m {}", e);
+            failed += 1;
+        }
+    }
+
+    println!(
+        "{} vector(s) checked, {} passed, {} failed",
+        vectors.len(),
+        vectors.len() - failed,
+        failed
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn write_vectors(mode: Mode, path: &str) {
+    let corpus = mode.generate_corpus();
+    if let Err(e) = std::fs::write(path, render_vectors(mode, &corpus)) {
+        error_out!("cannot write `{}`: {}", path, e);
+    }
+    println!("Wrote {} vector(s) to {}", corpus.len(), path);
+}
+
+fn print_help(exec: &str) {
+    println!("Usage:");
+    println!("\t{}                     write {}", exec, DEFAULT_OUTPUT);
+    println!("\t{} {} FILE             write the vectors to FILE", exec, FLAG_OUTPUT);
+    println!(
+        "\t{} {} [{} FILE]   write {} (or FILE) for pox_hash_words",
+        exec, FLAG_WORDS, FLAG_OUTPUT, DEFAULT_WORDS_OUTPUT
+    );
+    println!(
+        "\t{} {} [{} FILE]      write {} (or FILE) for pox_hash_md",
+        exec, FLAG_MD, FLAG_OUTPUT, DEFAULT_MD_OUTPUT
+    );
+    println!("\t{} {} FILE        check libpoxh.rs against FILE of any mode", exec, FLAG_CHECK);
+    println!("\t{} {}                  print this help", exec, FLAG_HELP);
+}
+
+fn main() {
+    let argv: Vec<String> = std::env::args().collect();
+    let exec = argv[0].rsplit('/').next().unwrap_or("poxh-vectors");
+    let args = argv[1..].iter().map(|a| a.as_str()).collect::<Vec<&str>>();
+
+    match args.as_slice() {
+        [] => write_vectors(Mode::Bytes, Mode::Bytes.default_output()),
+        [FLAG_OUTPUT, path] => write_vectors(Mode::Bytes, path),
+        [FLAG_WORDS] => write_vectors(Mode::Words, Mode::Words.default_output()),
+        [FLAG_WORDS, FLAG_OUTPUT, path] => write_vectors(Mode::Words, path),
+        [FLAG_MD] => write_vectors(Mode::Md, Mode::Md.default_output()),
+        [FLAG_MD, FLAG_OUTPUT, path] => write_vectors(Mode::Md, path),
+        [FLAG_CHECK, path] => check_vectors(path),
+        [FLAG_HELP] => print_help(exec),
+        _ => {
+            print_help(exec);
+            std::process::exit(2);
+        }
+    }
+}